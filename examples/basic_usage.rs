@@ -3,11 +3,9 @@ use std::fs::read_to_string;
 
 fn main() {
     let template_str = read_to_string("examples/template.mustache").unwrap();
-    let scheme_str = read_to_string("examples/scheme.yml").unwrap();
 
     let template = Template::new(template_str).unwrap();
-    let mut scheme: Scheme = serde_yaml::from_str(&scheme_str).unwrap();
-    scheme = scheme.create_slug();
+    let scheme = Scheme::from_path("examples/scheme.yml").unwrap();
 
     println!("{}", template.render(&scheme));
 }
@@ -0,0 +1,198 @@
+//! color conversion between sRGB and other color spaces
+//!
+//! Colors are represented as `[f64; 3]`. sRGB channels are in the range `0.0..=1.0`,
+//! hues are in degrees and everything else is in the units used by
+//! [CSS Color 4](https://www.w3.org/TR/css-color-4/).
+//!
+//! The matrices are taken from the [CSS Color 4 sample code](https://www.w3.org/TR/css-color-4/#color-conversion-code).
+
+use crate::scheme::RgbColor;
+
+/// white point of D50 in XYZ
+pub(crate) const D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+/// white point of D65 in XYZ
+pub(crate) const D65: [f64; 3] = [0.3127 / 0.3290, 1.0, (1.0 - 0.3127 - 0.3290) / 0.3290];
+
+/// constants of the CIE Lab conversion
+const KAPPA: f64 = 24389.0 / 27.0;
+const EPSILON: f64 = 216.0 / 24389.0;
+
+type Matrix = [[f64; 3]; 3];
+
+fn multiply(matrix: &Matrix, [x, y, z]: [f64; 3]) -> [f64; 3] {
+    matrix.map(|[a, b, c]| a * x + b * y + c * z)
+}
+
+/// Turn an sRGB color into an [`RgbColor`] by clipping every channel to `0.0..=1.0`.
+pub(crate) fn clip(srgb: [f64; 3]) -> RgbColor {
+    RgbColor(srgb.map(clip_channel))
+}
+
+/// Turn a channel in `0.0..=1.0` into a byte, clipping it to that range.
+pub(crate) fn clip_channel(channel: f64) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Turn an [`RgbColor`] into linear light sRGB.
+pub(crate) fn linear_srgb(RgbColor([r, g, b]): RgbColor) -> [f64; 3] {
+    [r, g, b].map(|channel| to_linear(channel as f64 / 255.0))
+}
+
+/// Convert a gamma encoded sRGB channel to linear light.
+pub(crate) fn to_linear(channel: f64) -> f64 {
+    let abs = channel.abs();
+    if abs <= 0.04045 {
+        channel / 12.92
+    } else {
+        channel.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear light channel to gamma encoded sRGB.
+pub(crate) fn from_linear(channel: f64) -> f64 {
+    let abs = channel.abs();
+    if abs <= 0.0031308 {
+        channel * 12.92
+    } else {
+        channel.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+pub(crate) fn hsl_to_srgb([hue, saturation, lightness]: [f64; 3]) -> [f64; 3] {
+    let channel = |n: f64| {
+        let k = (n + hue / 30.0).rem_euclid(12.0);
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [channel(0.0), channel(8.0), channel(4.0)]
+}
+
+pub(crate) fn hwb_to_srgb([hue, whiteness, blackness]: [f64; 3]) -> [f64; 3] {
+    if whiteness + blackness >= 1.0 {
+        let gray = whiteness / (whiteness + blackness);
+        return [gray; 3];
+    }
+    hsl_to_srgb([hue, 1.0, 0.5]).map(|channel| channel * (1.0 - whiteness - blackness) + whiteness)
+}
+
+/// Convert polar coordinates (lightness, chroma, hue) to rectangular ones (lightness, a, b).
+pub(crate) fn polar_to_rectangular([lightness, chroma, hue]: [f64; 3]) -> [f64; 3] {
+    let hue = hue.to_radians();
+    [lightness, chroma * hue.cos(), chroma * hue.sin()]
+}
+
+/// Convert rectangular coordinates (lightness, a, b) to polar ones (lightness, chroma, hue).
+///
+/// The hue is in the range `0.0..360.0`.
+/// Achromatic colors get a hue of `0.0`, instead of one based on rounding errors.
+pub(crate) fn rectangular_to_polar([lightness, a, b]: [f64; 3]) -> [f64; 3] {
+    const ACHROMATIC: f64 = 1e-4;
+
+    let chroma = a.hypot(b);
+    let hue = match chroma < ACHROMATIC {
+        true => 0.0,
+        false => b.atan2(a).to_degrees().rem_euclid(360.0),
+    };
+    [lightness, chroma, hue]
+}
+
+pub(crate) fn linear_srgb_to_oklab(linear_srgb: [f64; 3]) -> [f64; 3] {
+    #[rustfmt::skip]
+    const LINEAR_SRGB_TO_LMS: Matrix = [
+        [0.4122214708, 0.5363325363, 0.0514459929],
+        [0.2119034982, 0.6806995451, 0.1073969566],
+        [0.0883024619, 0.2817188376, 0.6299787005],
+    ];
+    #[rustfmt::skip]
+    const LMS_TO_OKLAB: Matrix = [
+        [0.2104542553, 0.7936177850, -0.0040720468],
+        [1.9779984951, -2.4285922050, 0.4505937099],
+        [0.0259040371, 0.7827717662, -0.8086757660],
+    ];
+
+    let lms = multiply(&LINEAR_SRGB_TO_LMS, linear_srgb).map(f64::cbrt);
+    multiply(&LMS_TO_OKLAB, lms)
+}
+
+pub(crate) fn oklab_to_linear_srgb(oklab: [f64; 3]) -> [f64; 3] {
+    #[rustfmt::skip]
+    const OKLAB_TO_LMS: Matrix = [
+        [1.0, 0.3963377774, 0.2158037573],
+        [1.0, -0.1055613458, -0.0638541728],
+        [1.0, -0.0894841775, -1.2914855480],
+    ];
+    #[rustfmt::skip]
+    const LMS_TO_LINEAR_SRGB: Matrix = [
+        [4.0767416621, -3.3077115913, 0.2309699292],
+        [-1.2684380046, 2.6097574011, -0.3413193965],
+        [-0.0041960863, -0.7034186147, 1.7076147010],
+    ];
+
+    let lms = multiply(&OKLAB_TO_LMS, oklab).map(|channel| channel.powi(3));
+    multiply(&LMS_TO_LINEAR_SRGB, lms)
+}
+
+/// Convert XYZ to CIE Lab relative to `white`.
+pub(crate) fn xyz_to_lab(xyz: [f64; 3], white: [f64; 3]) -> [f64; 3] {
+    let [x, y, z] = [0, 1, 2].map(|i| xyz[i] / white[i]);
+    let f = |value: f64| match value > EPSILON {
+        true => value.cbrt(),
+        false => (KAPPA * value + 16.0) / 116.0,
+    };
+    let [fx, fy, fz] = [f(x), f(y), f(z)];
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Convert CIE Lab relative to `white` to XYZ.
+pub(crate) fn lab_to_xyz([lightness, a, b]: [f64; 3], white: [f64; 3]) -> [f64; 3] {
+    let f1 = (lightness + 16.0) / 116.0;
+    let f0 = a / 500.0 + f1;
+    let f2 = f1 - b / 200.0;
+
+    let inverse = |f: f64| match f.powi(3) {
+        cube if cube > EPSILON => cube,
+        _ => (116.0 * f - 16.0) / KAPPA,
+    };
+    let y = match lightness > KAPPA * EPSILON {
+        true => f1.powi(3),
+        false => lightness / KAPPA,
+    };
+
+    let [wx, wy, wz] = white;
+    [inverse(f0) * wx, y * wy, inverse(f2) * wz]
+}
+
+/// Chromatic adaptation from D50 to D65 using the Bradford transform.
+pub(crate) fn xyz_d50_to_d65(xyz: [f64; 3]) -> [f64; 3] {
+    #[rustfmt::skip]
+    const D50_TO_D65: Matrix = [
+        [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+        [-0.0283697093338637, 1.0099953980813041, 0.021041441191917323],
+        [0.012314014864481998, -0.020507649298898964, 1.330365926242124],
+    ];
+    multiply(&D50_TO_D65, xyz)
+}
+
+/// Convert linear sRGB to XYZ relative to D65.
+pub(crate) fn linear_srgb_to_xyz(linear_srgb: [f64; 3]) -> [f64; 3] {
+    #[rustfmt::skip]
+    const LINEAR_SRGB_TO_XYZ: Matrix = [
+        [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+        [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+        [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+    ];
+    multiply(&LINEAR_SRGB_TO_XYZ, linear_srgb)
+}
+
+/// Convert XYZ relative to D65 to linear sRGB.
+pub(crate) fn xyz_to_linear_srgb(xyz: [f64; 3]) -> [f64; 3] {
+    #[rustfmt::skip]
+    const XYZ_TO_LINEAR_SRGB: Matrix = [
+        [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+        [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+        [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+    ];
+    multiply(&XYZ_TO_LINEAR_SRGB, xyz)
+}
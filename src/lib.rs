@@ -45,3 +45,5 @@ pub use ramhorns::Template;
 
 pub mod scheme;
 pub mod template;
+
+mod convert;
@@ -1,17 +1,36 @@
-use crate::template::{color_field::ColorField, TemplateField};
+use crate::template::{
+    color_field::{ColorField, FieldColor},
+    TemplateField,
+};
 use ramhorns::{encoding::Encoder, Content, Template};
-use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
 
 pub use self::{
     base_index::BaseIndex,
-    rgb_color::{RgbColor, RgbColorFormatter},
+    collection::{SchemeCollection, SlugCollision},
+    layout::{Layout, WithLayout},
+    load::{FileFormat, LoadError, LoadErrorKind},
+    meta_value::MetaValue,
+    rgb_color::{RgbColor, RgbColorError, RgbColorFormatter, RgbaColor},
+    system::{System, SystemMismatch},
+    validation::ValidationReport,
+    variant::Variant,
 };
 
 mod base_index;
+mod collection;
+mod layout;
+mod load;
+mod meta_value;
 mod rgb_color;
+mod system;
+mod validation;
+mod variant;
 
-/// type representing a base16 scheme
+/// type representing a base16 (or base24) scheme
 ///
 /// # Example
 ///
@@ -19,7 +38,7 @@ mod rgb_color;
 /// use base16_color_scheme::{Scheme, Template};
 ///
 /// let template = Template::new("\
-/// {{scheme-name}} {{scheme-slug}} {{scheme-author}}
+/// {{scheme-name}} {{scheme-slug}} {{scheme-author}} {{scheme-system}}
 /// {{base00-hex}} {{base00-hex-bgr}}
 /// {{base00-hex-r}} {{base00-hex-g}} {{base00-hex-b}}
 /// {{base00-rgb-r}} {{base00-rgb-g}} {{base00-rgb-b}}
@@ -37,7 +56,7 @@ mod rgb_color;
 /// assert_eq!(
 ///     template.render(&scheme),
 ///     "\
-/// Scheme Name scheme-name Scheme Author
+/// Scheme Name scheme-name Scheme Author base16
 /// 7cafc2 c2af7c
 /// 7c af c2
 /// 124 175 194
@@ -45,27 +64,96 @@ mod rgb_color;
 /// 196.29 0.36 0.62");
 /// ```
 ///
+/// # Sections
+///
+/// Every field can be used as a section, which is only rendered if the field exists,
+/// and as an inverted section, which is only rendered if it doesn't.
+/// Inside of a color section, the color can be referenced by its format alone.
+///
+/// ```rust
+/// use base16_color_scheme::{Scheme, Template};
+///
+/// let template = Template::new("\
+/// {{#base08-hex}}red: #{{hex}} {{rgb-r}}{{/base08-hex}}
+/// {{#base10-hex}}darker black: #{{base10-hex}}{{/base10-hex}}\
+/// {{^base10-hex}}darker black: #{{base00-hex}}{{/base10-hex}}
+/// {{^scheme-description}}no description{{/scheme-description}}").unwrap();
+///
+/// let scheme: Scheme = serde_yaml::from_str(r#"
+/// scheme: "Scheme Name"
+/// author: "Scheme Author"
+/// base00: "181818"
+/// base08: "ab4642"
+/// "#).unwrap();
+///
+/// assert_eq!(
+///     template.render(&scheme),
+///     "\
+/// red: #ab4642 171
+/// darker black: #181818
+/// no description");
+/// ```
+///
 /// # Serialization / Deserialization
 ///
-/// When deserializing Scheme requires the fields `scheme` and `author`,
+/// Scheme can be deserialized from two [layouts](Layout):
+///
+/// The [legacy layout](Layout::Legacy) requires the fields `scheme` and `author`,
 /// ignores the field `scheme-slug`,
 /// and accepts any number of fields between `base00` and `baseFF`, (any combination of uppercase and lowercase, so `baseab`, `baseAB`, and `baseAb` all work.)
-/// though for a normal base16 theme it should have at least `base00` to `base0F`.
+/// though for a normal base16 theme it should have at least `base00` to `base0F`
+/// and for a base24 theme `base00` to `base17`.
 ///
-/// Because `scheme-slug` is not created while deserialization it has to be inserted manually.
+/// The [tinted-theming layout](Layout::Tinted) requires the fields `name` and `author`,
+/// optionally accepts `system`, `slug`, `variant` and `description`
+/// and expects the colors nested in `palette`.
+///
+/// Both layouts can be mixed, so the layout doesn't have to be specified.
+///
+/// Any other field with a scalar value (string, bool or number) is stored as [`MetaValue`] in
+/// [`Scheme::metadata`] and serialized again after the known fields.
+/// Fields with nested values are skipped.
+/// Those fields can be used in templates as `{{scheme-meta-<key>}}`.
+/// Fields that look like a mistyped color slot, like `base0G` or `base100`, are an error
+/// instead of metadata.
+///
+/// If `system` is not specified [`Scheme::system`] is left empty,
+/// so [`Scheme::scheme_system`] detects it from the colors. (see [`System::from_indices`])
+///
+/// Unless the field `slug` is given, `scheme-slug` is not created while deserialization, so it has to be inserted manually.
 /// Either by setting [`Scheme::slug`] manually, or using [`Scheme::create_slug`].
 ///
-/// When serializing Scheme it first serializes [`Scheme::scheme`] and [`Scheme::author`] then ignores [`Scheme::slug`] as per [specification](https://github.com/chriskempson/base16/blob/main/file.md#scheme-files)
+/// When serializing Scheme it uses the [legacy layout](Layout::Legacy),
+/// so it first serializes [`Scheme::scheme`] and [`Scheme::author`] then ignores [`Scheme::slug`] as per [specification](https://github.com/chriskempson/base16/blob/main/file.md#scheme-files)
 /// and afterwards serializes all colors contained in [`Scheme::colors`] ordered by the field number.\
 /// (`base00`, `base01`, `base05` etc.)
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+/// [`Scheme::description`] is written after the author, like the other metadata,
+/// so it survives a round trip.
+/// The system and [`Scheme::variant`] are not part of the legacy layout,
+/// so they are not serialized.
+///
+/// To serialize to the tinted-theming layout use [`Scheme::with_layout`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Scheme {
     pub scheme: String,
     pub author: String,
-    #[serde(skip)]
     pub slug: String,
-    #[serde(flatten)]
+    /// system declared by the scheme file
+    ///
+    /// If this is `None` the system is detected from [`Self::colors`], see [`Self::scheme_system`].
+    pub system: Option<System>,
+    pub variant: Option<Variant>,
+    pub description: Option<String>,
+    pub metadata: BTreeMap<String, MetaValue>,
     pub colors: BTreeMap<BaseIndex, RgbColor>,
+    /// alpha channel of the colors in [`Self::colors`] that aren't opaque
+    ///
+    /// Slots without an entry are opaque.
+    /// An entry only belongs to the color of the same slot in [`Self::colors`],
+    /// so it has to be removed together with that color.
+    /// Entries of slots that have no color are ignored by [`Self::rgba`],
+    /// serialization and [`Self::validate`].
+    pub alpha: BTreeMap<BaseIndex, u8>,
 }
 
 impl Scheme {
@@ -89,12 +177,436 @@ impl Scheme {
         &self.author
     }
 
+    /// Return [`Self::variant`] or if it is not set, infer it from `base00` and `base07`.
+    ///
+    /// see [`Variant::detect`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::{scheme::Variant, Scheme, Template};
+    ///
+    /// let template = Template::new("\
+    /// {{scheme-variant}} {{scheme-is-light-variant}} \
+    /// {{#scheme-is-dark-variant}}dark{{/scheme-is-dark-variant}}\
+    /// {{^scheme-is-light-variant}}!light{{/scheme-is-light-variant}}").unwrap();
+    ///
+    /// let scheme: Scheme = serde_yaml::from_str(r#"
+    /// scheme: "Scheme Name"
+    /// author: "Scheme Author"
+    /// base00: "181818"
+    /// base07: "f8f8f8"
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(scheme.variant, None);
+    /// assert_eq!(scheme.scheme_variant(), Some(Variant::Dark));
+    /// assert_eq!(template.render(&scheme), "dark false dark!light");
+    /// ```
+    pub fn scheme_variant(&self) -> Option<Variant> {
+        self.variant.or_else(|| {
+            let background = self.colors.get(&BaseIndex(0x00))?;
+            let foreground = self.colors.get(&BaseIndex(0x07))?;
+            Some(Variant::detect(*background, *foreground))
+        })
+    }
+
+    pub fn scheme_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Look up `key` in [`Self::metadata`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::{scheme::MetaValue, Scheme, Template};
+    ///
+    /// let template = Template::new("\
+    /// {{scheme-description}}
+    /// {{scheme-meta-license}} {{scheme-meta-stars}} {{scheme-meta-base_url}}
+    /// {{scheme-meta-tagline}} {{{scheme-meta-tagline}}}").unwrap();
+    ///
+    /// let scheme: Scheme = serde_yaml::from_str(r#"
+    /// scheme: "Scheme Name"
+    /// author: "Scheme Author"
+    /// description: "A scheme"
+    /// license: "MIT"
+    /// stars: 42
+    /// base_url: "https://example.com"
+    /// tagline: "Salt & Pepper"
+    /// tags: ["dark", "blue"]
+    /// base00: "7cafc2"
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(scheme.scheme_meta("license"), Some(&MetaValue::from("MIT")));
+    /// assert_eq!(scheme.scheme_meta("stars"), Some(&MetaValue::Integer(42)));
+    /// // nested values are skipped
+    /// assert_eq!(scheme.scheme_meta("tags"), None);
+    /// assert_eq!(
+    ///     template.render(&scheme),
+    ///     "A scheme\nMIT 42 https://example.com\nSalt &amp; Pepper Salt & Pepper"
+    /// );
+    /// assert_eq!(
+    ///     serde_yaml::to_string(&scheme).unwrap(),
+    ///     "\
+    /// scheme: Scheme Name
+    /// author: Scheme Author
+    /// description: A scheme
+    /// base_url: https://example.com
+    /// license: MIT
+    /// stars: 42
+    /// tagline: Salt & Pepper
+    /// base00: 7cafc2
+    /// "
+    /// );
+    /// // only the skipped nested values are lost in a round trip
+    /// let legacy = serde_yaml::to_string(&scheme).unwrap();
+    /// assert_eq!(serde_yaml::from_str::<Scheme>(&legacy).unwrap(), scheme);
+    /// ```
+    pub fn scheme_meta(&self, key: &str) -> Option<&MetaValue> {
+        self.metadata.get(key)
+    }
+
+    /// Look up the color at `index` in [`Self::colors`] together with its [alpha](Self::alpha).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::scheme::{BaseIndex, RgbColor, RgbaColor, Scheme};
+    ///
+    /// let scheme: Scheme = serde_yaml::from_str(r##"
+    /// scheme: "Scheme Name"
+    /// author: "Scheme Author"
+    /// base00: "#7cafc280"
+    /// base01: "#7cafc2"
+    /// "##).unwrap();
+    ///
+    /// assert_eq!(scheme.colors[&BaseIndex(0x00)], RgbColor([0x7c, 0xaf, 0xc2]));
+    /// assert_eq!(
+    ///     scheme.rgba(BaseIndex(0x00)),
+    ///     Some(RgbaColor::new([0x7c, 0xaf, 0xc2], 0x80))
+    /// );
+    /// assert_eq!(scheme.rgba(BaseIndex(0x01)), Some(RgbColor([0x7c, 0xaf, 0xc2]).into()));
+    /// assert_eq!(scheme.rgba(BaseIndex(0x02)), None);
+    /// ```
+    pub fn rgba(&self, index: BaseIndex) -> Option<RgbaColor> {
+        let alpha = self.alpha.get(&index).copied().unwrap_or(RgbaColor::OPAQUE);
+        Some(self.colors.get(&index)?.with_alpha(alpha))
+    }
+
+    /// Iterate over [`Self::colors`] together with their [alpha](Self::alpha).
+    pub fn rgba_colors(&self) -> impl Iterator<Item = (BaseIndex, RgbaColor)> + '_ {
+        self.colors
+            .keys()
+            .filter_map(|&index| Some((index, self.rgba(index)?)))
+    }
+
+    /// Return a [`Serialize`](serde::Serialize) wrapper that serializes this scheme using `layout`.
+    ///
+    /// see [`Layout`]
+    pub fn with_layout(&self, layout: Layout) -> WithLayout<'_> {
+        WithLayout {
+            scheme: self,
+            layout,
+        }
+    }
+
+    /// Return [`Self::system`] or if it is not set, detect it from the indices used in
+    /// [`Self::colors`].
+    ///
+    /// see [`System::from_indices`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::{
+    ///     scheme::{BaseIndex, RgbColor, System},
+    ///     Scheme,
+    /// };
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut scheme = Scheme {
+    ///     colors: (0x00..=0x17)
+    ///         .map(|index| (BaseIndex(index), RgbColor([index, index, index])))
+    ///         .collect::<BTreeMap<_, _>>(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(scheme.scheme_system(), System::Base24);
+    ///
+    /// scheme.system = Some(System::Base16);
+    /// assert_eq!(scheme.scheme_system(), System::Base16);
+    /// ```
+    pub fn scheme_system(&self) -> System {
+        self.system
+            .clone()
+            .unwrap_or_else(|| System::from_indices(self.colors.keys().copied()))
+    }
+
+    /// Render `template` like [`Template::render`], but report the colors the template uses
+    /// which are missing from this scheme instead of silently rendering them empty.
+    ///
+    /// Colors that are only used as (inverted) sections are optional and never reported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::{
+    ///     scheme::{BaseIndex, System},
+    ///     Scheme, Template,
+    /// };
+    ///
+    /// let template = Template::new("{{base00-hex}} {{{base10-hex}}}").unwrap();
+    /// let scheme: Scheme = serde_yaml::from_str(r#"
+    /// scheme: "Scheme Name"
+    /// author: "Scheme Author"
+    /// base00: "7cafc2"
+    /// "#).unwrap();
+    ///
+    /// let error = scheme.render_checked(&template).unwrap_err();
+    /// assert_eq!(error.scheme, System::Base16);
+    /// assert_eq!(error.template, System::Base24);
+    /// assert_eq!(error.missing.into_iter().collect::<Vec<_>>(), [BaseIndex(0x10)]);
+    ///
+    /// // colors that are only used in sections are optional
+    /// let template = Template::new("{{base00-hex}} {{^base10-hex}}{{base00-hex}}{{/base10-hex}}")
+    ///     .unwrap();
+    /// assert_eq!(scheme.render_checked(&template).unwrap(), "7cafc2 7cafc2");
+    /// ```
+    pub fn render_checked(&self, template: &Template) -> Result<String, SystemMismatch> {
+        let checked = CheckedScheme {
+            scheme: self,
+            used: RefCell::default(),
+        };
+        let rendered = template.render(&checked);
+
+        let used = checked.used.into_inner();
+        let missing: BTreeSet<BaseIndex> = used
+            .iter()
+            .filter(|index| !self.colors.contains_key(index))
+            .copied()
+            .collect();
+
+        if missing.is_empty() {
+            Ok(rendered)
+        } else {
+            Err(SystemMismatch {
+                scheme: self.scheme_system(),
+                template: System::from_indices(used),
+                missing,
+            })
+        }
+    }
+
     /// Look up the field in [`Self::color`] and return a [formatter](RgbColorFormatter) for it.\
     /// (If the field exists.)
-    pub fn color(&self, ColorField { number, format }: ColorField) -> Option<RgbColorFormatter> {
-        self.colors
-            .get(&BaseIndex(number))
-            .map(|&color| RgbColorFormatter { color, format })
+    ///
+    /// The [mix](crate::template::color_field::Mix) and
+    /// [modifier](crate::template::color_field::Modifier) of the field are applied to the color,
+    /// which is then replaced with an opaque [readable foreground](Self::readable_foreground)
+    /// if the field asks for one.
+    /// If the mixed in color doesn't exist, this returns `None` as well.
+    pub fn color(
+        &self,
+        ColorField {
+            number,
+            mix,
+            modifier,
+            foreground,
+            format,
+            number_format,
+        }: ColorField,
+    ) -> Option<RgbColorFormatter> {
+        let RgbaColor { color, alpha } = self.field_color(FieldColor {
+            number,
+            mix,
+            modifier,
+            foreground,
+        })?;
+        Some(RgbColorFormatter {
+            color,
+            alpha,
+            format,
+            number_format,
+        })
+    }
+
+    /// Look up the color of a field and apply its mix, modifier and foreground,
+    /// see [`Self::color`].
+    pub(crate) fn field_color(
+        &self,
+        FieldColor {
+            number,
+            mix,
+            modifier,
+            foreground,
+        }: FieldColor,
+    ) -> Option<RgbaColor> {
+        let color = self.rgba(BaseIndex(number))?;
+        let RgbaColor { color, alpha } = match mix {
+            Some(mix) => mix.apply(color, self.rgba(BaseIndex(mix.with))?),
+            None => color,
+        };
+        let color = match modifier {
+            Some(modifier) => modifier.apply(color),
+            None => color,
+        };
+        Some(match foreground {
+            Some(level) => self.readable_foreground(color, level.ratio()).into(),
+            None => color.with_alpha(alpha),
+        })
+    }
+
+    /// Pick the color of `base00` to `base07` with the highest contrast ratio against
+    /// `background`.
+    ///
+    /// If none of them reaches `min_contrast`, black or white is picked instead.
+    /// (see [`RgbColor::contrast_ratio`] and [`ContrastLevel`](crate::template::color_field::ContrastLevel))
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::{scheme::RgbColor, Scheme, Template};
+    ///
+    /// let scheme: Scheme = serde_yaml::from_str("
+    /// scheme: Scheme Name
+    /// author: Scheme Author
+    /// base00: 181818
+    /// base05: d8d8d8
+    /// base07: f8f8f8
+    /// base0A: f7ca88
+    /// base0D: 6d6d6d
+    /// ").unwrap();
+    ///
+    /// let background = RgbColor([0xf7, 0xca, 0x88]);
+    /// assert_eq!(
+    ///     scheme.readable_foreground(background, 4.5),
+    ///     RgbColor([0x18, 0x18, 0x18])
+    /// );
+    ///
+    /// let template = Template::new("{{base0A-fg-hex}} {{base0D-fg-hex}} {{base0D-fg-aaa-hex}}")
+    ///     .unwrap();
+    /// assert_eq!(template.render(&scheme), "181818 f8f8f8 ffffff");
+    /// ```
+    pub fn readable_foreground(&self, background: RgbColor, min_contrast: f64) -> RgbColor {
+        const BLACK: RgbColor = RgbColor([0x00, 0x00, 0x00]);
+        const WHITE: RgbColor = RgbColor([0xff, 0xff, 0xff]);
+
+        let contrast = |color: &RgbColor| color.contrast_ratio(background);
+        let best = |candidates: &mut dyn Iterator<Item = RgbColor>| {
+            candidates.max_by(|a, b| contrast(a).total_cmp(&contrast(b)))
+        };
+
+        best(
+            &mut self
+                .colors
+                .range(BaseIndex(0x00)..=BaseIndex(0x07))
+                .map(|(_, &color)| color),
+        )
+        .filter(|color| contrast(color) >= min_contrast)
+        .or_else(|| best(&mut [BLACK, WHITE].into_iter()))
+        .unwrap()
+    }
+
+    /// slug used for the `scheme-slug` field
+    fn field_slug(&self) -> &str {
+        match self.scheme_slug() {
+            "" => "scheme-slug",
+            slug => slug,
+        }
+    }
+
+    /// Render the field `name`, escaped for `{{field}}` or unescaped for `{{{field}}}`.
+    fn render_field<E: Encoder>(
+        &self,
+        name: &str,
+        encoder: &mut E,
+        escape: bool,
+    ) -> Result<bool, E::Error> {
+        use TemplateField::{
+            ColorField, SchemeAuthor, SchemeDescription, SchemeIsDarkVariant, SchemeIsLightVariant,
+            SchemeMeta, SchemeName, SchemeSlug, SchemeSystem, SchemeVariant, UnparsableField,
+        };
+
+        fn render<T: Content, E: Encoder>(
+            value: Option<T>,
+            encoder: &mut E,
+            escape: bool,
+        ) -> Result<bool, E::Error> {
+            let Some(value) = value else {
+                return Ok(false);
+            };
+            match escape {
+                true => value.render_escaped(encoder)?,
+                false => value.render_unescaped(encoder)?,
+            }
+            Ok(true)
+        }
+
+        match TemplateField::parse_field(name) {
+            SchemeName => render(Some(self.scheme_name()), encoder, escape),
+            SchemeAuthor => render(Some(self.scheme_author()), encoder, escape),
+            SchemeSlug => render(Some(self.field_slug()), encoder, escape),
+            SchemeSystem => render(Some(self.scheme_system().as_str()), encoder, escape),
+            SchemeDescription => render(self.scheme_description(), encoder, escape),
+            SchemeMeta(key) => render(self.scheme_meta(key), encoder, escape),
+            SchemeVariant => render(
+                self.scheme_variant().map(|variant| variant.as_str()),
+                encoder,
+                escape,
+            ),
+            SchemeIsLightVariant => render(
+                Some(self.scheme_variant() == Some(Variant::Light)),
+                encoder,
+                escape,
+            ),
+            SchemeIsDarkVariant => render(
+                Some(self.scheme_variant() == Some(Variant::Dark)),
+                encoder,
+                escape,
+            ),
+            ColorField(color_field) => render(self.color(color_field), encoder, escape),
+            UnparsableField => Ok(false),
+        }
+    }
+
+    /// Render `section` with `value` as context if it exists and is truthy.
+    fn render_value<T, C, E>(
+        &self,
+        value: Option<T>,
+        section: ramhorns::Section<C>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        T: Content,
+        C: ramhorns::traits::ContentSequence,
+        E: Encoder,
+    {
+        match value {
+            Some(value) => {
+                if value.is_truthy() {
+                    section.with(&value).render(encoder)?;
+                }
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Render `section` if `condition` is true, used for boolean sections.
+    fn render_condition<C, E>(
+        &self,
+        condition: bool,
+        section: ramhorns::Section<C>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        C: ramhorns::traits::ContentSequence,
+        E: Encoder,
+    {
+        if condition {
+            section.render(encoder)?;
+        }
+        Ok(true)
     }
 }
 
@@ -137,19 +649,59 @@ impl Content for Scheme {
         name: &str,
         encoder: &mut E,
     ) -> Result<bool, E::Error> {
-        use TemplateField::{ColorField, SchemeAuthor, SchemeName, SchemeSlug, UnparsableField};
+        self.render_field(name, encoder, true)
+    }
+
+    fn render_field_unescaped<E: Encoder>(
+        &self,
+        _hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        self.render_field(name, encoder, false)
+    }
+
+    fn render_field_section<C, E>(
+        &self,
+        _hash: u64,
+        name: &str,
+        section: ramhorns::Section<C>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        C: ramhorns::traits::ContentSequence,
+        E: Encoder,
+    {
+        use TemplateField::{
+            ColorField, SchemeAuthor, SchemeDescription, SchemeIsDarkVariant, SchemeIsLightVariant,
+            SchemeMeta, SchemeName, SchemeSlug, SchemeSystem, SchemeVariant, UnparsableField,
+        };
 
         match TemplateField::parse_field(name) {
-            SchemeName => encoder.write_escaped(self.scheme_name()).map(|_| true),
-            SchemeAuthor => encoder.write_escaped(self.scheme_author()).map(|_| true),
-            SchemeSlug => match self.scheme_slug() {
-                "" => encoder.write_escaped("scheme-slug").map(|_| true),
-                slug => encoder.write_escaped(slug).map(|_| true),
-            },
-            ColorField(color_field) => match self.color(color_field) {
-                Some(value) => value.render_escaped(encoder).map(|_| true),
-                None => Ok(false),
-            },
+            SchemeName => self.render_value(Some(self.scheme_name()), section, encoder),
+            SchemeAuthor => self.render_value(Some(self.scheme_author()), section, encoder),
+            SchemeSlug => self.render_value(Some(self.field_slug()), section, encoder),
+            SchemeSystem => {
+                self.render_value(Some(self.scheme_system().as_str()), section, encoder)
+            }
+            SchemeDescription => self.render_value(self.scheme_description(), section, encoder),
+            SchemeMeta(key) => self.render_value(self.scheme_meta(key), section, encoder),
+            SchemeVariant => self.render_value(
+                self.scheme_variant().map(|variant| variant.as_str()),
+                section,
+                encoder,
+            ),
+            SchemeIsLightVariant => self.render_condition(
+                self.scheme_variant() == Some(Variant::Light),
+                section,
+                encoder,
+            ),
+            SchemeIsDarkVariant => self.render_condition(
+                self.scheme_variant() == Some(Variant::Dark),
+                section,
+                encoder,
+            ),
+            ColorField(color_field) => self.render_value(self.color(color_field), section, encoder),
             UnparsableField => Ok(false),
         }
     }
@@ -165,20 +717,106 @@ impl Content for Scheme {
         C: ramhorns::traits::ContentSequence,
         E: Encoder,
     {
-        use TemplateField::{ColorField, SchemeAuthor, SchemeName, SchemeSlug, UnparsableField};
+        use TemplateField::{
+            ColorField, SchemeAuthor, SchemeDescription, SchemeIsDarkVariant, SchemeIsLightVariant,
+            SchemeMeta, SchemeName, SchemeSlug, SchemeSystem, SchemeVariant, UnparsableField,
+        };
 
-        match TemplateField::parse_field(name) {
-            SchemeName => encoder.write_escaped(self.scheme_name()).map(|_| true),
-            SchemeAuthor => encoder.write_escaped(self.scheme_author()).map(|_| true),
-            SchemeSlug => match self.scheme_slug() {
-                "" => encoder.write_escaped("scheme-slug").map(|_| true),
-                slug => encoder.write_escaped(slug).map(|_| true),
-            },
-            ColorField(color_field) => match self.color(color_field) {
-                Some(value) => value.render_inverse(section, encoder).map(|_| true),
-                None => Ok(false),
-            },
-            UnparsableField => Ok(false),
+        let condition = match TemplateField::parse_field(name) {
+            SchemeName => !self.scheme_name().is_empty(),
+            SchemeAuthor => !self.scheme_author().is_empty(),
+            SchemeSlug => !self.field_slug().is_empty(),
+            SchemeSystem => true,
+            SchemeDescription => self
+                .scheme_description()
+                .is_some_and(|description| !description.is_empty()),
+            SchemeMeta(key) => self.scheme_meta(key).is_some_and(|value| value.is_truthy()),
+            SchemeVariant => self.scheme_variant().is_some(),
+            SchemeIsLightVariant => self.scheme_variant() == Some(Variant::Light),
+            SchemeIsDarkVariant => self.scheme_variant() == Some(Variant::Dark),
+            ColorField(color_field) => self.color(color_field).is_some(),
+            UnparsableField => return Ok(false),
+        };
+        self.render_condition(!condition, section, encoder)
+    }
+}
+
+/// wrapper around [`Scheme`] used by [`Scheme::render_checked`] to record which colors a
+/// template uses
+struct CheckedScheme<'a> {
+    scheme: &'a Scheme,
+    used: RefCell<BTreeSet<BaseIndex>>,
+}
+
+impl CheckedScheme<'_> {
+    fn record(&self, name: &str) {
+        if let TemplateField::ColorField(ColorField { number, mix, .. }) =
+            TemplateField::parse_field(name)
+        {
+            let mut used = self.used.borrow_mut();
+            used.insert(BaseIndex(number));
+            used.extend(mix.map(|mix| BaseIndex(mix.with)));
         }
     }
 }
+
+impl Content for CheckedScheme<'_> {
+    fn is_truthy(&self) -> bool {
+        self.scheme.is_truthy()
+    }
+
+    fn capacity_hint(&self, tpl: &Template) -> usize {
+        self.scheme.capacity_hint(tpl)
+    }
+
+    fn render_field_escaped<E: Encoder>(
+        &self,
+        hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        self.record(name);
+        self.scheme.render_field_escaped(hash, name, encoder)
+    }
+
+    fn render_field_unescaped<E: Encoder>(
+        &self,
+        hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        self.record(name);
+        self.scheme.render_field_unescaped(hash, name, encoder)
+    }
+
+    fn render_field_section<C, E>(
+        &self,
+        hash: u64,
+        name: &str,
+        section: ramhorns::Section<C>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        C: ramhorns::traits::ContentSequence,
+        E: Encoder,
+    {
+        // sections only render if the field exists, so they don't require it
+        self.scheme
+            .render_field_section(hash, name, section, encoder)
+    }
+
+    fn render_field_inverse<C, E>(
+        &self,
+        hash: u64,
+        name: &str,
+        section: ramhorns::Section<C>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        C: ramhorns::traits::ContentSequence,
+        E: Encoder,
+    {
+        self.scheme
+            .render_field_inverse(hash, name, section, encoder)
+    }
+}
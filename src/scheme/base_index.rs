@@ -60,7 +60,7 @@ impl<'de> Visitor<'de> for BaseIndexVisitor {
                 .map(|[v]: [u8; 1]| BaseIndex(v))
                 .map_err(E::custom)
         } else {
-            Err(E::custom(format!(r#"should start with "base""#)))
+            Err(E::custom(r#"should start with "base""#))
         }
     }
 }
@@ -0,0 +1,234 @@
+use crate::scheme::{FileFormat, LoadError, System};
+use crate::Scheme;
+use std::{
+    collections::BTreeMap,
+    fs::{self, FileType},
+    path::{Path, PathBuf},
+};
+
+/// collection of schemes indexed by their [system](Scheme::scheme_system) and slug
+///
+/// A base16 and a base24 scheme may share a slug, since they are different schemes.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "yaml")] {
+/// use base16_color_scheme::scheme::{SchemeCollection, System};
+///
+/// let collection = SchemeCollection::from_dir("examples").unwrap();
+///
+/// assert_eq!(collection.len(), 1);
+/// assert!(collection.errors().is_empty());
+/// assert_eq!(
+///     collection.get(&System::Base16, "scheme-name").unwrap().scheme,
+///     "Scheme Name"
+/// );
+/// assert!(collection.get(&System::Base24, "scheme-name").is_none());
+/// assert_eq!(collection.by_name("scheme name").unwrap().slug, "scheme-name");
+/// assert_eq!(collection.by_system(System::Base16).count(), 1);
+/// assert_eq!(
+///     collection.path(&System::Base16, "scheme-name"),
+///     Some("examples/scheme.yml".as_ref())
+/// );
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct SchemeCollection {
+    schemes: BTreeMap<(System, String), Entry>,
+    errors: Vec<LoadError>,
+    collisions: Vec<SlugCollision>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    path: Option<PathBuf>,
+    scheme: Scheme,
+}
+
+/// two schemes of the same system with the same slug were added to a [`SchemeCollection`]
+///
+/// The first scheme is kept, the second one is rejected.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SlugCollision {
+    pub system: System,
+    pub slug: String,
+    /// path of the scheme that was kept, if it was loaded from a file
+    pub kept: Option<PathBuf>,
+    /// path of the scheme that was rejected, if it was loaded from a file
+    pub rejected: Option<PathBuf>,
+}
+
+impl SchemeCollection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recursively load every scheme file in `dir`.
+    ///
+    /// Only files with a known [extension](FileFormat::from_path) are loaded,
+    /// and they are loaded in the order of their paths.
+    /// Symbolic links to files are loaded, but symbolic links to directories are not followed,
+    /// so a link cycle can't make loading recurse forever.
+    /// Files or subdirectories that fail to load don't abort loading the rest,
+    /// instead their errors are collected in [`SchemeCollection::errors`].
+    ///
+    /// Returns an error if `dir` itself can't be read.
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let mut collection = Self::new();
+        collection.load_dir(dir.as_ref())?;
+        Ok(collection)
+    }
+
+    /// Recursively load every scheme file in `dir` into this collection.
+    ///
+    /// See [`SchemeCollection::from_dir`].
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<(), LoadError> {
+        let dir = dir.as_ref();
+        for (path, file_type) in read_dir(dir)? {
+            if file_type.is_dir() {
+                if let Err(error) = self.load_dir(&path) {
+                    self.errors.push(error);
+                }
+            } else if FileFormat::from_path(&path).is_some() {
+                match Scheme::from_path(&path) {
+                    Ok(scheme) => {
+                        self.insert_entry(Entry {
+                            path: Some(path),
+                            scheme,
+                        });
+                    }
+                    Err(error) => self.errors.push(error),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add `scheme` to the collection.
+    ///
+    /// Returns `false` and records a [`SlugCollision`] if a scheme of the same system with the
+    /// same slug already exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::{
+    ///     scheme::{SchemeCollection, System},
+    ///     Scheme,
+    /// };
+    ///
+    /// let scheme = Scheme {
+    ///     slug: "scheme-name".to_owned(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut collection = SchemeCollection::new();
+    /// assert!(collection.insert(scheme.clone()));
+    /// assert!(!collection.insert(scheme.clone()));
+    /// assert_eq!(collection.len(), 1);
+    /// assert_eq!(collection.collisions()[0].slug, "scheme-name");
+    ///
+    /// // the base24 variant of a scheme may use the same slug
+    /// let base24 = Scheme {
+    ///     system: Some(System::Base24),
+    ///     ..scheme
+    /// };
+    /// assert!(collection.insert(base24));
+    /// assert_eq!(collection.len(), 2);
+    /// assert_eq!(collection.by_system(System::Base24).count(), 1);
+    /// ```
+    pub fn insert(&mut self, scheme: Scheme) -> bool {
+        self.insert_entry(Entry { path: None, scheme })
+    }
+
+    fn insert_entry(&mut self, entry: Entry) -> bool {
+        let key = (entry.scheme.scheme_system(), entry.scheme.slug.clone());
+        match self.schemes.get(&key) {
+            Some(kept) => {
+                let (system, slug) = key;
+                self.collisions.push(SlugCollision {
+                    system,
+                    slug,
+                    kept: kept.path.clone(),
+                    rejected: entry.path,
+                });
+                false
+            }
+            None => {
+                self.schemes.insert(key, entry);
+                true
+            }
+        }
+    }
+
+    /// Look up a scheme of `system` by its slug.
+    pub fn get(&self, system: &System, slug: &str) -> Option<&Scheme> {
+        self.entry(system, slug).map(|entry| &entry.scheme)
+    }
+
+    fn entry(&self, system: &System, slug: &str) -> Option<&Entry> {
+        self.schemes.get(&(system.clone(), slug.to_owned()))
+    }
+
+    /// Look up the first scheme (ordered by system and slug) whose name matches `name`,
+    /// ignoring case.
+    pub fn by_name(&self, name: &str) -> Option<&Scheme> {
+        self.iter()
+            .find(|scheme| scheme.scheme.to_lowercase() == name.to_lowercase())
+    }
+
+    /// All schemes of `system`, ordered by slug.
+    pub fn by_system(&self, system: System) -> impl Iterator<Item = &Scheme> {
+        self.schemes
+            .iter()
+            .filter(move |((scheme_system, _), _)| *scheme_system == system)
+            .map(|(_, entry)| &entry.scheme)
+    }
+
+    /// path of the file the scheme of `system` with `slug` was loaded from
+    pub fn path(&self, system: &System, slug: &str) -> Option<&Path> {
+        self.entry(system, slug)?.path.as_deref()
+    }
+
+    /// All schemes, ordered by system and slug.
+    pub fn iter(&self) -> impl Iterator<Item = &Scheme> {
+        self.schemes.values().map(|entry| &entry.scheme)
+    }
+
+    pub fn len(&self) -> usize {
+        self.schemes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.schemes.is_empty()
+    }
+
+    /// errors of the files and directories that failed to load
+    pub fn errors(&self) -> &[LoadError] {
+        &self.errors
+    }
+
+    /// schemes that were rejected because their slug was already taken within their system
+    pub fn collisions(&self) -> &[SlugCollision] {
+        &self.collisions
+    }
+}
+
+/// Read the entries of `dir` with their file types, sorted by path.
+///
+/// The file types don't follow symbolic links.
+fn read_dir(dir: &Path) -> Result<Vec<(PathBuf, FileType)>, LoadError> {
+    let with_path = |error: std::io::Error| LoadError {
+        path: Some(dir.to_owned()),
+        ..LoadError::from(error)
+    };
+
+    let mut paths = fs::read_dir(dir)
+        .map_err(with_path)?
+        .map(|entry| entry.and_then(|entry| Ok((entry.path(), entry.file_type()?))))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(with_path)?;
+    paths.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(paths)
+}
@@ -0,0 +1,413 @@
+use crate::scheme::{create_slug, BaseIndex, MetaValue, RgbaColor, Scheme};
+use serde::{
+    de::{
+        self, EnumAccess, IgnoredAny, IntoDeserializer, MapAccess, SeqAccess, VariantAccess,
+        Visitor,
+    },
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Formatter},
+};
+
+/// layout of a scheme file
+///
+/// [`Scheme`] deserializes both layouts transparently.
+/// For serialization the layout can be chosen with [`Scheme::with_layout`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base16_color_scheme::{
+///     scheme::{BaseIndex, Layout, RgbColor, System, Variant},
+///     Scheme,
+/// };
+///
+/// let scheme: Scheme = serde_yaml::from_str(r##"
+/// system: "base16"
+/// name: "Scheme Name"
+/// slug: "scheme-name"
+/// author: "Scheme Author"
+/// variant: "dark"
+/// palette:
+///   base00: "#7cafc2"
+///   base01: "#7cafc280"
+/// "##).unwrap();
+///
+/// assert_eq!(scheme.scheme, "Scheme Name");
+/// assert_eq!(scheme.slug, "scheme-name");
+/// assert_eq!(scheme.system, Some(System::Base16));
+/// assert_eq!(scheme.variant, Some(Variant::Dark));
+/// assert_eq!(scheme.colors[&BaseIndex(0x00)], RgbColor([0x7c, 0xaf, 0xc2]));
+/// assert_eq!(scheme.colors[&BaseIndex(0x01)], RgbColor([0x7c, 0xaf, 0xc2]));
+/// assert_eq!(scheme.alpha[&BaseIndex(0x01)], 0x80);
+///
+/// assert_eq!(
+///     serde_yaml::to_string(&scheme.with_layout(Layout::Legacy)).unwrap(),
+///     "\
+/// scheme: Scheme Name
+/// author: Scheme Author
+/// base00: 7cafc2
+/// base01: 7cafc280
+/// "
+/// );
+/// assert_eq!(
+///     serde_yaml::to_string(&scheme.with_layout(Layout::Tinted)).unwrap(),
+///     "\
+/// system: base16
+/// name: Scheme Name
+/// slug: scheme-name
+/// author: Scheme Author
+/// variant: dark
+/// palette:
+///   base00: '#7cafc2'
+///   base01: '#7cafc280'
+/// "
+/// );
+///
+/// // the missing name is reported with the key of the layout that is used
+/// let error = serde_yaml::from_str::<Scheme>("
+/// author: Scheme Author
+/// palette:
+///   base00: '#7cafc2'
+/// ").unwrap_err();
+/// assert!(error.to_string().starts_with("missing field `name`"));
+///
+/// // keys that look like a color slot have to be valid slots
+/// for key in ["base0G", "base100", "baseOA"] {
+///     let error = serde_yaml::from_str::<Scheme>(&format!("
+/// scheme: Scheme Name
+/// author: Scheme Author
+/// {key}: ff0000
+/// ")).unwrap_err();
+///     assert!(error.to_string().starts_with(&format!("invalid color slot `{key}`")));
+/// }
+/// // while other keys starting with `base` are metadata
+/// let scheme: Scheme = serde_yaml::from_str("
+/// scheme: Scheme Name
+/// author: Scheme Author
+/// based-on: Another Scheme
+/// baseline: 12
+/// ").unwrap();
+/// assert_eq!(scheme.metadata.len(), 2);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layout {
+    /// the flat layout defined by the [base16 specification](https://github.com/chriskempson/base16/blob/main/file.md#scheme-files)
+    /// (`scheme`, `author`, `base00`, ...)
+    #[default]
+    Legacy,
+    /// the layout defined by the [tinted-theming specification 0.11](https://github.com/tinted-theming/home/blob/main/styling.md)
+    /// (`system`, `name`, `slug`, `author`, `variant`, `description`, `palette`)
+    Tinted,
+}
+
+/// [`Serialize`] wrapper returned by [`Scheme::with_layout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WithLayout<'a> {
+    pub scheme: &'a Scheme,
+    pub layout: Layout,
+}
+
+impl Serialize for WithLayout<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let scheme = self.scheme;
+        let mut map = serializer.serialize_map(None)?;
+
+        match self.layout {
+            Layout::Legacy => {
+                map.serialize_entry("scheme", &scheme.scheme)?;
+                map.serialize_entry("author", &scheme.author)?;
+                if let Some(description) = &scheme.description {
+                    map.serialize_entry("description", description)?;
+                }
+                for (key, value) in &scheme.metadata {
+                    map.serialize_entry(key, value)?;
+                }
+                for (index, color) in scheme.rgba_colors() {
+                    map.serialize_entry(&index, &color)?;
+                }
+            }
+            Layout::Tinted => {
+                map.serialize_entry("system", &scheme.scheme_system())?;
+                map.serialize_entry("name", &scheme.scheme)?;
+                match scheme.scheme_slug() {
+                    "" => map.serialize_entry("slug", &create_slug(scheme.scheme_name()))?,
+                    slug => map.serialize_entry("slug", slug)?,
+                }
+                map.serialize_entry("author", &scheme.author)?;
+                if let Some(variant) = &scheme.variant {
+                    map.serialize_entry("variant", variant)?;
+                }
+                if let Some(description) = &scheme.description {
+                    map.serialize_entry("description", description)?;
+                }
+                for (key, value) in &scheme.metadata {
+                    map.serialize_entry(key, value)?;
+                }
+                map.serialize_entry("palette", &Palette(scheme))?;
+            }
+        }
+
+        map.end()
+    }
+}
+
+/// serializes the colors of a [`Layout::Tinted`] scheme with a leading `#`
+struct Palette<'a>(&'a Scheme);
+
+impl Serialize for Palette<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.colors.len()))?;
+        for (index, color) in self.0.rgba_colors() {
+            map.serialize_entry(&index, &format_args!("#{color}"))?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Scheme {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.with_layout(Layout::Legacy).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Scheme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SchemeVisitor)
+    }
+}
+
+struct SchemeVisitor;
+
+impl<'de> Visitor<'de> for SchemeVisitor {
+    type Value = Scheme;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a base16 or tinted-theming scheme")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut name = None;
+        let mut author = None;
+        let mut slug = None;
+        let mut system = None;
+        let mut variant = None;
+        let mut description = None;
+        let mut metadata = BTreeMap::new();
+        let mut colors = BTreeMap::new();
+        let mut tinted = false;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "scheme" | "name" => name = Some(map.next_value()?),
+                "author" => author = Some(map.next_value()?),
+                "slug" => {
+                    tinted = true;
+                    slug = Some(map.next_value()?);
+                }
+                "scheme-slug" => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+                "system" => {
+                    tinted = true;
+                    system = Some(map.next_value()?);
+                }
+                "variant" => variant = Some(map.next_value()?),
+                "description" => description = Some(map.next_value()?),
+                "palette" => {
+                    tinted = true;
+                    colors.extend(map.next_value::<BTreeMap<BaseIndex, RgbaColor>>()?);
+                }
+                // keys like `base_url` which aren't a valid index are metadata as well
+                _ => match BaseIndex::deserialize(
+                    IntoDeserializer::<de::value::Error>::into_deserializer(key.as_str()),
+                ) {
+                    Ok(index) => {
+                        colors.insert(index, map.next_value()?);
+                    }
+                    // but a typo in a slot like `base0G` shouldn't silently drop the color
+                    Err(error) if is_slot_like(&key) => {
+                        return Err(de::Error::custom(format_args!(
+                            "invalid color slot `{key}`: {error}"
+                        )));
+                    }
+                    Err(_) => {
+                        if let Metadata(Some(value)) = map.next_value()? {
+                            metadata.insert(key, value);
+                        }
+                    }
+                },
+            }
+        }
+
+        let alpha = colors
+            .iter()
+            .filter(|(_, color)| !color.is_opaque())
+            .map(|(&index, color)| (index, color.alpha))
+            .collect();
+        let colors = colors
+            .into_iter()
+            .map(|(index, color)| (index, color.color))
+            .collect();
+
+        Ok(Scheme {
+            scheme: name
+                .ok_or_else(|| de::Error::missing_field(if tinted { "name" } else { "scheme" }))?,
+            author: author.ok_or_else(|| de::Error::missing_field("author"))?,
+            slug: slug.unwrap_or_default(),
+            system,
+            variant,
+            description,
+            metadata,
+            colors,
+            alpha,
+        })
+    }
+}
+
+/// Check if `key` is meant to be a color slot, which is `base` followed by
+/// letters and digits that start with a digit or are two characters long.
+///
+/// This catches `base0G` or `base100`, but leaves `based-on` or `baseline` to the metadata.
+fn is_slot_like(key: &str) -> bool {
+    key.strip_prefix("base").is_some_and(|index| {
+        !index.is_empty()
+            && index.chars().all(|c| c.is_ascii_alphanumeric())
+            && (index.starts_with(|c: char| c.is_ascii_digit()) || index.len() == 2)
+    })
+}
+
+/// value of a [`Scheme::metadata`] entry
+///
+/// Any scalar (string, bool or number) is accepted and keeps its type.
+/// Nested values (sequences and maps) and `null` are skipped, so they are [`None`].
+struct Metadata(Option<MetaValue>);
+
+impl<'de> Deserialize<'de> for Metadata {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(MetadataVisitor)
+    }
+}
+
+struct MetadataVisitor;
+
+impl<'de> Visitor<'de> for MetadataVisitor {
+    type Value = Metadata;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Metadata(Some(MetaValue::Bool(v))))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Metadata(Some(MetaValue::Integer(v))))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Metadata(Some(match i64::try_from(v) {
+            Ok(v) => MetaValue::Integer(v),
+            Err(_) => MetaValue::Unsigned(v),
+        })))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Metadata(Some(MetaValue::Float(v))))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Metadata(Some(MetaValue::String(v.to_owned()))))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Metadata(Some(MetaValue::String(v))))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Metadata(None))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Metadata(None))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Metadata::deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(Metadata(None))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+        Ok(Metadata(None))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        data.variant::<IgnoredAny>()?
+            .1
+            .newtype_variant::<IgnoredAny>()?;
+        Ok(Metadata(None))
+    }
+}
@@ -0,0 +1,322 @@
+use crate::Scheme;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs::File,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// file format of a scheme file
+///
+/// Every format is only supported if the cargo feature of the same name is enabled.
+/// (`yaml` is enabled by default)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FileFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl FileFormat {
+    /// Detect the format based on the extension of `path`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::scheme::FileFormat;
+    ///
+    /// assert_eq!(FileFormat::from_path("scheme.yml"), Some(FileFormat::Yaml));
+    /// assert_eq!(FileFormat::from_path("scheme.JSON"), Some(FileFormat::Json));
+    /// assert_eq!(FileFormat::from_path("scheme.toml"), Some(FileFormat::Toml));
+    /// assert_eq!(FileFormat::from_path("scheme"), None);
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "yaml" | "yml" => Some(Self::Yaml),
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+
+    /// Detect the format based on the content of a scheme file.
+    ///
+    /// Content starting with `{` is JSON, content whose first line is a `key = value` pair or
+    /// a `[table]` is TOML and everything else is YAML.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::scheme::FileFormat;
+    ///
+    /// assert_eq!(FileFormat::from_content(r#"{ "scheme": "Scheme Name" }"#), FileFormat::Json);
+    /// assert_eq!(FileFormat::from_content(r#"scheme = "Scheme Name""#), FileFormat::Toml);
+    /// assert_eq!(FileFormat::from_content(r#"scheme: "Scheme Name""#), FileFormat::Yaml);
+    /// ```
+    pub fn from_content(content: &str) -> Self {
+        let first_line = content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .unwrap_or_default();
+
+        if first_line.starts_with('{') {
+            return Self::Json;
+        }
+
+        let is_toml_pair = match (first_line.find('='), first_line.find(':')) {
+            (Some(equals), Some(colon)) => equals < colon,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if is_toml_pair || first_line.starts_with('[') {
+            Self::Toml
+        } else {
+            Self::Yaml
+        }
+    }
+
+    /// name of the cargo feature that enables this format
+    pub fn feature(&self) -> &'static str {
+        match self {
+            Self::Yaml => "yaml",
+            Self::Json => "json",
+            Self::Toml => "toml",
+        }
+    }
+}
+
+impl Scheme {
+    /// Load a scheme from the file at `path`.
+    ///
+    /// The [format](FileFormat) is detected based on the file extension,
+    /// or if that is unknown based on the content.
+    /// If the scheme doesn't contain a slug, it is created using [`Scheme::create_slug`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "yaml")] {
+    /// use base16_color_scheme::Scheme;
+    ///
+    /// let scheme = Scheme::from_path("examples/scheme.yml").unwrap();
+    /// assert_eq!(scheme.slug, "scheme-name");
+    ///
+    /// let error = Scheme::from_path("examples/template.mustache").unwrap_err();
+    /// assert_eq!(error.path.as_deref(), Some("examples/template.mustache".as_ref()));
+    /// # }
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        let with_path = |error: LoadError| LoadError {
+            path: Some(path.to_owned()),
+            ..error
+        };
+
+        let content = read(
+            File::open(path)
+                .map_err(LoadError::from)
+                .map_err(with_path)?,
+        )
+        .map_err(with_path)?;
+        let format =
+            FileFormat::from_path(path).unwrap_or_else(|| FileFormat::from_content(&content));
+
+        Self::from_str_with_format(&content, format).map_err(with_path)
+    }
+
+    /// Load a scheme from `reader`.
+    ///
+    /// The [format](FileFormat) is detected based on the content.
+    /// If the scheme doesn't contain a slug, it is created using [`Scheme::create_slug`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "yaml")] {
+    /// use base16_color_scheme::Scheme;
+    ///
+    /// let scheme = Scheme::from_reader("scheme: Scheme Name\nauthor: Scheme Author".as_bytes()).unwrap();
+    /// assert_eq!(scheme.slug, "scheme-name");
+    ///
+    /// let error = Scheme::from_reader("scheme: Scheme Name\nauthor: Scheme Author\nbase00: nope".as_bytes())
+    ///     .unwrap_err();
+    /// assert_eq!(error.location, Some((3, 9)));
+    /// # }
+    /// ```
+    ///
+    /// With the feature `json`:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "json")] {
+    /// use base16_color_scheme::{scheme::LoadErrorKind, Scheme};
+    ///
+    /// let scheme = Scheme::from_reader(r#"{ "scheme": "Scheme Name", "author": "Scheme Author" }"#.as_bytes())
+    ///     .unwrap();
+    /// assert_eq!(scheme.slug, "scheme-name");
+    ///
+    /// let error = Scheme::from_reader("{\n  \"scheme\": \"Scheme Name\",\n  \"base00\": \"nope\"\n}".as_bytes())
+    ///     .unwrap_err();
+    /// assert!(matches!(error.kind, LoadErrorKind::Json(_)));
+    /// assert_eq!(error.location, Some((3, 18)));
+    /// # }
+    /// ```
+    ///
+    /// With the feature `toml`, where the location is taken from the span of the error:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "toml")] {
+    /// use base16_color_scheme::{scheme::LoadErrorKind, Scheme};
+    ///
+    /// let scheme = Scheme::from_reader("scheme = \"Scheme Name\"\nauthor = \"Scheme Author\"".as_bytes())
+    ///     .unwrap();
+    /// assert_eq!(scheme.slug, "scheme-name");
+    ///
+    /// let error = Scheme::from_reader("scheme = \"Scheme Name\"\nauthor = \"Scheme Author\"\nbase00 = \"nope\"".as_bytes())
+    ///     .unwrap_err();
+    /// assert!(matches!(error.kind, LoadErrorKind::Toml(_)));
+    /// assert_eq!(error.location, Some((3, 10)));
+    /// # }
+    /// ```
+    pub fn from_reader(reader: impl Read) -> Result<Self, LoadError> {
+        let content = read(reader)?;
+        Self::from_str_with_format(&content, FileFormat::from_content(&content))
+    }
+
+    /// Deserialize a scheme from `content` using `format`.
+    ///
+    /// If the scheme doesn't contain a slug, it is created using [`Scheme::create_slug`].
+    #[cfg_attr(
+        not(any(feature = "yaml", feature = "json", feature = "toml")),
+        allow(unused_variables)
+    )]
+    pub fn from_str_with_format(content: &str, format: FileFormat) -> Result<Self, LoadError> {
+        let scheme: Scheme = match format {
+            #[cfg(feature = "yaml")]
+            FileFormat::Yaml => serde_yaml::from_str(content).map_err(|error| LoadError {
+                location: error
+                    .location()
+                    .map(|location| (location.line(), location.column())),
+                ..LoadError::from(LoadErrorKind::Yaml(error))
+            }),
+            #[cfg(feature = "json")]
+            FileFormat::Json => serde_json::from_str(content).map_err(|error| LoadError {
+                location: Some((error.line(), error.column())),
+                ..LoadError::from(LoadErrorKind::Json(error))
+            }),
+            #[cfg(feature = "toml")]
+            FileFormat::Toml => toml::from_str(content).map_err(|error| LoadError {
+                location: error.span().map(|span| line_column(content, span.start)),
+                ..LoadError::from(LoadErrorKind::Toml(Box::new(error)))
+            }),
+            #[allow(unreachable_patterns)]
+            format => Err(LoadError::from(LoadErrorKind::Disabled(format))),
+        }?;
+
+        Ok(match scheme.scheme_slug() {
+            "" => scheme.create_slug(),
+            _ => scheme,
+        })
+    }
+}
+
+fn read(mut reader: impl Read) -> Result<String, LoadError> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+/// Turn a byte offset into a 1-based line and column.
+#[cfg(feature = "toml")]
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |index| index + 1) + 1;
+    (line, column)
+}
+
+/// Error returned by [`Scheme::from_path`] and [`Scheme::from_reader`]
+#[derive(Debug)]
+pub struct LoadError {
+    /// path of the file that failed to load
+    pub path: Option<PathBuf>,
+    /// 1-based line and column of the error in the file, if known
+    pub location: Option<(usize, usize)>,
+    pub kind: LoadErrorKind,
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LoadErrorKind {
+    Io(io::Error),
+    /// the file has a format whose cargo feature is disabled
+    Disabled(FileFormat),
+    #[cfg(feature = "yaml")]
+    Yaml(serde_yaml::Error),
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    #[cfg(feature = "toml")]
+    Toml(Box<toml::de::Error>),
+}
+
+impl From<LoadErrorKind> for LoadError {
+    fn from(kind: LoadErrorKind) -> Self {
+        Self {
+            path: None,
+            location: None,
+            kind,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(error: io::Error) -> Self {
+        LoadErrorKind::Io(error).into()
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        if let Some((line, column)) = self.location {
+            write!(f, "{line}:{column}:")?;
+        }
+        if self.path.is_some() || self.location.is_some() {
+            f.write_str(" ")?;
+        }
+
+        match &self.kind {
+            LoadErrorKind::Io(error) => write!(f, "{error}"),
+            LoadErrorKind::Disabled(format) => write!(
+                f,
+                "{format:?} schemes are not supported, enable the feature {:?}",
+                format.feature()
+            ),
+            #[cfg(feature = "yaml")]
+            LoadErrorKind::Yaml(error) => write!(f, "{error}"),
+            #[cfg(feature = "json")]
+            LoadErrorKind::Json(error) => write!(f, "{error}"),
+            #[cfg(feature = "toml")]
+            LoadErrorKind::Toml(error) => write!(f, "{}", error.message()),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            LoadErrorKind::Io(error) => Some(error),
+            LoadErrorKind::Disabled(_) => None,
+            #[cfg(feature = "yaml")]
+            LoadErrorKind::Yaml(error) => Some(error),
+            #[cfg(feature = "json")]
+            LoadErrorKind::Json(error) => Some(error),
+            #[cfg(feature = "toml")]
+            LoadErrorKind::Toml(error) => Some(error.as_ref()),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use ramhorns::{encoding::Encoder, Content, Template};
+use serde::Serialize;
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+/// value of a [`Scheme::metadata`](crate::Scheme::metadata) entry
+///
+/// It keeps the type the value had in the scheme file, so it is serialized the same way again.
+///
+/// # Examples
+///
+/// ```rust
+/// use base16_color_scheme::scheme::MetaValue;
+///
+/// assert_eq!(MetaValue::Integer(42).to_string(), "42");
+/// assert_eq!(serde_yaml::to_string(&MetaValue::Bool(true)).unwrap(), "true\n");
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum MetaValue {
+    Bool(bool),
+    Integer(i64),
+    /// only used for integers that don't fit into an [`i64`]
+    Unsigned(u64),
+    Float(f64),
+    String(String),
+}
+
+impl MetaValue {
+    /// position of the variant, used to order values of different types
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Bool(_) => 0,
+            Self::Integer(_) => 1,
+            Self::Unsigned(_) => 2,
+            Self::Float(_) => 3,
+            Self::String(_) => 4,
+        }
+    }
+}
+
+impl PartialEq for MetaValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MetaValue {}
+
+impl PartialOrd for MetaValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MetaValue {
+    /// Floats are compared with [`f64::total_cmp`], values of different types are ordered by
+    /// their variant.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+            (Self::Unsigned(a), Self::Unsigned(b)) => a.cmp(b),
+            (Self::Float(a), Self::Float(b)) => a.total_cmp(b),
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl Display for MetaValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::Integer(value) => value.fmt(f),
+            Self::Unsigned(value) => value.fmt(f),
+            Self::Float(value) => value.fmt(f),
+            Self::String(value) => f.write_str(value),
+        }
+    }
+}
+
+impl From<String> for MetaValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for MetaValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl Content for MetaValue {
+    fn is_truthy(&self) -> bool {
+        match self {
+            Self::Bool(value) => value.is_truthy(),
+            Self::Integer(value) => value.is_truthy(),
+            Self::Unsigned(value) => value.is_truthy(),
+            Self::Float(value) => value.is_truthy(),
+            Self::String(value) => value.is_truthy(),
+        }
+    }
+
+    fn capacity_hint(&self, tpl: &Template) -> usize {
+        match self {
+            Self::String(value) => value.capacity_hint(tpl),
+            _ => 5,
+        }
+    }
+
+    fn render_escaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        match self {
+            Self::String(value) => encoder.write_escaped(value),
+            _ => encoder.format_unescaped(self),
+        }
+    }
+
+    fn render_unescaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        match self {
+            Self::String(value) => encoder.write_unescaped(value),
+            _ => encoder.format_unescaped(self),
+        }
+    }
+}
@@ -1,32 +1,136 @@
-use serde::{Deserialize, Serialize};
+use self::{functional::from_functional, named::NAMED_COLORS};
+use crate::convert;
+use hex::{FromHex, FromHexError};
+use serde::{de::Visitor, Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    str::FromStr,
+};
 
 pub use formatter::RgbColorFormatter;
 
 mod formatter;
+mod functional;
+mod named;
 
 /// type representing a hex rgb color, which implements [`Serialize`](serde::Serialize)/[`Deserialze`](serde::Deserialize)
 ///
 /// it uses [`hex`] for the implementation so it's deserialization is case insensitive and it's
 /// serialization is lower case by default.
 ///
+/// Deserialization uses [`RgbColor::from_str`], so it also accepts
+/// - a leading `#` or `0x`, (`#7cafc2`, `0x7cafc2`)
+/// - short hex colors, (`#fff`, `fff`)
+/// - [CSS named colors](https://www.w3.org/TR/css-color-4/#named-colors), (`rebeccapurple`)
+/// - the CSS functional notations `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`, `oklab()` and `oklch()`.
+///   (`rgb(124 175 194)`, `hsl(196 36% 62%)`, `oklch(0.72 0.06 220)`)
+///
+/// Colors in functional notation that lie outside of the sRGB gamut are clipped,
+/// by clamping each of the converted red, green and blue channels to the valid range.
+/// As specified by CSS, `lab()` and `lch()` are relative to the D50 white point.
+///
+/// Colors with an alpha channel that isn't opaque are rejected, use [`RgbaColor`] for those.
+///
+/// Serialization always writes 6 lowercase hex digits without prefix.
+///
 /// # Examples
 ///
 /// ```rust
-/// use base16_color_scheme::scheme::RgbColor;
+/// use base16_color_scheme::scheme::{RgbColor, RgbColorError};
 ///
 /// let color: RgbColor = serde_yaml::from_str("7CaFc2").unwrap();
 /// assert_eq!(color, RgbColor([0x7c, 0xaf, 0xc2]));
 /// assert_eq!(serde_yaml::to_string(&color).unwrap(), "7cafc2\n");
+///
+/// let color: RgbColor = serde_yaml::from_str("'#7cafc2'").unwrap();
+/// assert_eq!(color, RgbColor([0x7c, 0xaf, 0xc2]));
+///
+/// let color: RgbColor = serde_yaml::from_str("0x7CAFC2").unwrap();
+/// assert_eq!(color, RgbColor([0x7c, 0xaf, 0xc2]));
+///
+/// let color: RgbColor = serde_yaml::from_str("'#fff'").unwrap();
+/// assert_eq!(color, RgbColor([0xff, 0xff, 0xff]));
+///
+/// let color: RgbColor = serde_yaml::from_str("RebeccaPurple").unwrap();
+/// assert_eq!(color, RgbColor([0x66, 0x33, 0x99]));
+/// assert_eq!(serde_yaml::to_string(&color).unwrap(), "'663399'\n");
+///
+/// let color: RgbColor = serde_yaml::from_str("rgb(124 175 194)").unwrap();
+/// assert_eq!(color, RgbColor([0x7c, 0xaf, 0xc2]));
+///
+/// let color: RgbColor = serde_yaml::from_str("hsl(196 36% 62%)").unwrap();
+/// assert_eq!(color, RgbColor([0x7b, 0xae, 0xc1]));
+///
+/// let color: RgbColor = serde_yaml::from_str("oklch(0.72 0.06 220)").unwrap();
+/// assert_eq!(color, RgbColor([0x7a, 0xae, 0xbf]));
+///
+/// let color: RgbColor = serde_yaml::from_str("lab(68 -10 -15)").unwrap();
+/// assert_eq!(color, RgbColor([0x85, 0xac, 0xc0]));
+///
+/// // out of gamut colors are clipped
+/// let color: RgbColor = serde_yaml::from_str("rgb(300, 100%, -20)").unwrap();
+/// assert_eq!(color, RgbColor([0xff, 0xff, 0x00]));
+///
+/// // an opaque alpha channel is fine, anything else needs an `RgbaColor`
+/// assert_eq!("#7cafc2ff".parse(), Ok(RgbColor([0x7c, 0xaf, 0xc2])));
+/// assert_eq!("#7cafc280".parse::<RgbColor>(), Err(RgbColorError::Alpha));
+///
+/// // arguments have to be finite numbers
+/// assert_eq!(
+///     "rgb(nan nan nan)".parse::<RgbColor>(),
+///     Err(RgbColorError::InvalidFunction)
+/// );
+/// assert_eq!(
+///     "rgb(inf 0 0)".parse::<RgbColor>(),
+///     Err(RgbColorError::InvalidFunction)
+/// );
+///
+/// // nothing may follow the closing parenthesis
+/// assert_eq!(
+///     "rgb(1 2 3)garbage".parse::<RgbColor>(),
+///     Err(RgbColorError::InvalidFunction)
+/// );
+/// assert_eq!(
+///     "hsl(0 0% 0%) junk".parse::<RgbColor>(),
+///     Err(RgbColorError::InvalidFunction)
+/// );
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RgbColor(pub [u8; 3]);
 
+impl RgbColor {
+    /// Add an alpha channel to the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::scheme::{RgbColor, RgbaColor};
+    ///
+    /// let color = RgbColor([0x7c, 0xaf, 0xc2]).with_alpha(0x80);
+    /// assert_eq!(color, RgbaColor::new([0x7c, 0xaf, 0xc2], 0x80));
+    /// assert_eq!(color.to_string(), "7cafc280");
+    /// ```
+    pub fn with_alpha(self, alpha: u8) -> RgbaColor {
+        RgbaColor { color: self, alpha }
+    }
+}
+
+impl Display for RgbColor {
+    /// Write the color as 6 lowercase hex digits.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let RgbColor([r, g, b]) = *self;
+        write!(f, "{r:02x}{g:02x}{b:02x}")
+    }
+}
+
 impl Serialize for RgbColor {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        hex::serialize(self.0, serializer)
+        serializer.collect_str(self)
     }
 }
 
@@ -35,6 +139,250 @@ impl<'de> Deserialize<'de> for RgbColor {
     where
         D: serde::Deserializer<'de>,
     {
-        hex::deserialize(deserializer).map(RgbColor)
+        deserializer.deserialize_str(ColorVisitor(PhantomData))
+    }
+}
+
+impl FromStr for RgbColor {
+    type Err = RgbColorError;
+
+    /// Parse the color like [`RgbaColor::from_str`], but reject colors that aren't opaque.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.parse()? {
+            RgbaColor {
+                color,
+                alpha: RgbaColor::OPAQUE,
+            } => Ok(color),
+            _ => Err(RgbColorError::Alpha),
+        }
+    }
+}
+
+/// [`RgbColor`] with an alpha channel, which implements [`Serialize`](serde::Serialize)/[`Deserialze`](serde::Deserialize)
+///
+/// It accepts everything [`RgbColor`] does, and additionally
+/// - 8 digit hex colors, (`#7cafc280`)
+/// - short hex colors with alpha, (`#fff8`)
+/// - the `transparent` named color,
+/// - an alpha value in functional notation. (`hsl(196 36% 62% / 50%)`)
+///
+/// Serialization writes 6 lowercase hex digits without prefix, or 8 if the color isn't opaque.
+/// (see [`Display`])
+///
+/// # Examples
+///
+/// ```rust
+/// use base16_color_scheme::scheme::{RgbColor, RgbaColor};
+///
+/// let color: RgbaColor = serde_yaml::from_str("'#7cafc280'").unwrap();
+/// assert_eq!(color, RgbaColor::new([0x7c, 0xaf, 0xc2], 0x80));
+/// assert_eq!(serde_yaml::to_string(&color).unwrap(), "7cafc280\n");
+///
+/// let color: RgbaColor = serde_yaml::from_str("hsl(196 36% 62% / 50%)").unwrap();
+/// assert_eq!(color, RgbaColor::new([0x7b, 0xae, 0xc1], 0x80));
+///
+/// let color: RgbaColor = serde_yaml::from_str("7cafc2").unwrap();
+/// assert_eq!(color, RgbColor([0x7c, 0xaf, 0xc2]).into());
+/// assert_eq!(serde_yaml::to_string(&color).unwrap(), "7cafc2\n");
+///
+/// let color: RgbaColor = "transparent".parse().unwrap();
+/// assert_eq!(color.alpha, 0x00);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RgbaColor {
+    pub color: RgbColor,
+    pub alpha: u8,
+}
+
+impl RgbaColor {
+    /// alpha value of an opaque color
+    pub const OPAQUE: u8 = 0xff;
+
+    pub fn new(rgb: [u8; 3], alpha: u8) -> Self {
+        Self {
+            color: RgbColor(rgb),
+            alpha,
+        }
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        self.alpha == Self::OPAQUE
+    }
+}
+
+impl From<RgbColor> for RgbaColor {
+    /// the color made opaque
+    fn from(color: RgbColor) -> Self {
+        color.with_alpha(Self::OPAQUE)
+    }
+}
+
+impl Display for RgbaColor {
+    /// Write the color as 6 lowercase hex digits, or 8 if it isn't opaque.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.color.fmt(f)?;
+        if !self.is_opaque() {
+            write!(f, "{:02x}", self.alpha)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for RgbaColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RgbaColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ColorVisitor(PhantomData))
+    }
+}
+
+impl FromStr for RgbaColor {
+    type Err = RgbColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+
+        if let Some(hex) = ["#", "0x", "0X"]
+            .into_iter()
+            .find_map(|prefix| input.strip_prefix(prefix))
+        {
+            return from_hex(hex);
+        }
+
+        if input.contains('(') {
+            return from_functional(input);
+        }
+
+        if input.chars().all(|c| c.is_ascii_hexdigit()) {
+            return from_hex(input);
+        }
+
+        let name = input.to_ascii_lowercase();
+        if name == "transparent" {
+            return Ok(RgbaColor::new([0x00, 0x00, 0x00], 0x00));
+        }
+
+        NAMED_COLORS
+            .binary_search_by_key(&name.as_str(), |&(name, _)| name)
+            .map(|index| RgbColor(NAMED_COLORS[index].1).into())
+            .map_err(|_| RgbColorError::UnknownName)
+    }
+}
+
+/// visitor parsing [`RgbColor`] or [`RgbaColor`] with their [`FromStr`] implementation
+struct ColorVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for ColorVisitor<T>
+where
+    T: FromStr<Err = RgbColorError>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a hex color, CSS named color or CSS color function")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// Decode 3, 4, 6 or 8 hex digits.
+fn from_hex(hex: &str) -> Result<RgbaColor, RgbColorError> {
+    let expanded;
+    let hex = match hex.len() {
+        3 | 4 => {
+            expanded = hex.chars().flat_map(|c| [c, c]).collect::<String>();
+            &expanded
+        }
+        _ => hex,
+    };
+
+    match hex.len() {
+        8 => <[u8; 4]>::from_hex(hex).map(|[r, g, b, a]| RgbaColor::new([r, g, b], a)),
+        _ => <[u8; 3]>::from_hex(hex).map(|rgb| RgbColor(rgb).into()),
+    }
+    .map_err(RgbColorError::Hex)
+}
+
+/// Error returned by [`RgbColor::from_str`] and [`RgbaColor::from_str`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RgbColorError {
+    /// the color is written in hex, but isn't a valid 3, 4, 6 or 8 digit hex number
+    Hex(FromHexError),
+    /// the color isn't written in hex and isn't a CSS named color either
+    UnknownName,
+    /// the color is written in functional notation, but the function is unknown
+    UnknownFunction,
+    /// the color is written in functional notation, but the syntax or arguments are invalid
+    InvalidFunction,
+    /// the color has an alpha channel that isn't opaque, which only [`RgbaColor`] can represent
+    Alpha,
+}
+
+impl Display for RgbColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hex(error) => write!(f, "invalid hex color: {error}"),
+            Self::UnknownName => f.write_str("unknown color name"),
+            Self::UnknownFunction => f.write_str("unknown color function"),
+            Self::InvalidFunction => f.write_str("invalid color function syntax or arguments"),
+            Self::Alpha => f.write_str("color isn't opaque"),
+        }
+    }
+}
+
+impl Error for RgbColorError {}
+
+impl RgbColor {
+    /// Relative luminance as defined by [WCAG 2](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::scheme::RgbColor;
+    ///
+    /// assert_eq!(RgbColor([0x00, 0x00, 0x00]).relative_luminance(), 0.0);
+    /// assert_eq!(RgbColor([0xff, 0xff, 0xff]).relative_luminance(), 1.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f64 {
+        let [r, g, b] = convert::linear_srgb(*self);
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Contrast ratio between `self` and `other` as defined by
+    /// [WCAG 2](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio), ranging from 1 to 21
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::scheme::RgbColor;
+    ///
+    /// let black = RgbColor([0x00, 0x00, 0x00]);
+    /// let white = RgbColor([0xff, 0xff, 0xff]);
+    ///
+    /// assert_eq!(black.contrast_ratio(white), 21.0);
+    /// assert_eq!(white.contrast_ratio(black), 21.0);
+    /// assert_eq!(white.contrast_ratio(white), 1.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: RgbColor) -> f64 {
+        let [lighter, darker] = match self.relative_luminance() > other.relative_luminance() {
+            true => [self.relative_luminance(), other.relative_luminance()],
+            false => [other.relative_luminance(), self.relative_luminance()],
+        };
+        (lighter + 0.05) / (darker + 0.05)
     }
 }
@@ -1,13 +1,22 @@
 use crate::{
-    scheme::rgb_color::RgbColor,
-    template::color_field::{Dec, Format, Hex, Hsl, HslFormatter, Rgb},
+    scheme::rgb_color::{RgbColor, RgbaColor},
+    template::color_field::{
+        parse_section_field, AnsiFormatter, Css, Dec, Float, Format, Hex, Hsl, HslFormatter, Hsv,
+        HsvFormatter, Hwb, HwbFormatter, Lab, LabFormatter, Lch, LchFormatter, NumberFormat, Oklab,
+        OklabFormatter, Oklch, OklchFormatter, Packed, Radix, Rgb, Rgb16, Xyz, XyzFormatter,
+    },
 };
 use ramhorns::{encoding::Encoder, Content};
 use std::fmt::{self, Display, Formatter};
 
 /// formatter that formats a color according to the [specification](https://github.com/chriskempson/base16/blob/main/builder.md#template-tags)
 ///
-/// This formatter contains a color and a specifier in what format this color should be formatted.
+/// This formatter contains a color, its alpha channel and a specifier in what format this color
+/// should be formatted.
+///
+/// The [`NumberFormat`] is honored by formats that write a single floating point number,
+/// all other formats ignore it. (A [`ColorField`](crate::template::color_field::ColorField)
+/// doesn't accept one after them.)
 ///
 /// # Example
 ///
@@ -18,8 +27,10 @@ use std::fmt::{self, Display, Formatter};
 /// #             format!(
 /// #                 "{}",
 /// #                 RgbColorFormatter {
-/// #                     color: $color,
-/// #                     format: $format
+/// #                     color: $color.color,
+/// #                     alpha: $color.alpha,
+/// #                     format: $format,
+/// #                     number_format: Default::default(),
 /// #                 }
 /// #             ),
 /// #             $expected
@@ -31,67 +42,331 @@ use std::fmt::{self, Display, Formatter};
 /// # }
 /// #
 /// use base16_color_scheme::{
-///     scheme::{RgbColor, RgbColorFormatter},
-///     template::color_field::{Dec, Format, Hex, Hsl, Rgb},
+///     scheme::{RgbColorFormatter, RgbaColor},
+///     template::color_field::{
+///         Css, Dec, Float, Format, Hex, HexPrefix, HexStyle, Hsl, Hsv, Hwb, Lab, Lch,
+///         NumberFormat, Oklab, Oklch, Packed, Radix, Rgb, Rgb16, Xyz,
+///     },
 /// };
 ///
-/// let color = RgbColor([0x7c, 0xaf, 0xc2]);
+/// let color = RgbaColor::new([0x7c, 0xaf, 0xc2], 0x80);
+/// let default = HexStyle::default();
+/// let upper = HexStyle { prefix: HexPrefix::Hash, uppercase: true };
 ///
 /// // This macro is used to test the result of format!("{}") in a much more readable fashion
 /// rgb_formatter_eq! {
 ///     color,
-///     Format::Hex(Hex::Rgb), "7cafc2";
-///     Format::Hex(Hex::R),   "7c";
-///     Format::Hex(Hex::G),   "af";
-///     Format::Hex(Hex::B),   "c2";
-///     Format::Hex(Hex::Bgr), "c2af7c";
-///     Format::Rgb(Rgb::R),   "124";
-///     Format::Rgb(Rgb::G),   "175";
-///     Format::Rgb(Rgb::B),   "194";
-///     Format::Dec(Dec::R),   "0.49";
-///     Format::Dec(Dec::G),   "0.69";
-///     Format::Dec(Dec::B),   "0.76";
-///     Format::Hsl(Hsl::H),   "196.29";
-///     Format::Hsl(Hsl::S),   "0.36";
-///     Format::Hsl(Hsl::L),   "0.62";
+///     Format::Hex(Hex::Rgb, default),               "7cafc2";
+///     Format::Hex(Hex::R, default),                 "7c";
+///     Format::Hex(Hex::G, default),                 "af";
+///     Format::Hex(Hex::B, default),                 "c2";
+///     Format::Hex(Hex::Bgr, default),               "c2af7c";
+///     Format::Hex(Hex::A, default),                 "80";
+///     Format::Hex(Hex::Rgba, default),              "7cafc280";
+///     Format::Hex(Hex::Argb, default),              "807cafc2";
+///     Format::Hex(Hex::Rgb, upper),                 "#7CAFC2";
+///     Format::Hex(Hex::Argb, upper),                "#807CAFC2";
+///     Format::Rgb(Rgb::R),                          "124";
+///     Format::Rgb(Rgb::G),                          "175";
+///     Format::Rgb(Rgb::B),                          "194";
+///     Format::Rgb(Rgb::A),                          "128";
+///     Format::Rgb16(Rgb16::R),                      "31868";
+///     Format::Rgb16(Rgb16::G),                      "44975";
+///     Format::Rgb16(Rgb16::B),                      "49858";
+///     Format::Rgb16(Rgb16::A),                      "32896";
+///     Format::Xrgb,                                 "rgb:7c7c/afaf/c2c2";
+///     Format::Dec(Dec::R),                          "0.49";
+///     Format::Dec(Dec::G),                          "0.69";
+///     Format::Dec(Dec::B),                          "0.76";
+///     Format::Dec(Dec::A),                          "0.50";
+///     Format::Float(Float::R),                      "0.48627450980392156";
+///     Format::Float(Float::G),                      "0.6862745098039216";
+///     Format::Float(Float::B),                      "0.7607843137254902";
+///     Format::Float(Float::A),                      "0.5019607843137255";
+///     Format::Hsl(Hsl::H),                          "196.29";
+///     Format::Hsl(Hsl::S),                          "0.36";
+///     Format::Hsl(Hsl::L),                          "0.62";
+///     Format::Hsv(Hsv::H),                          "196.29";
+///     Format::Hsv(Hsv::S),                          "0.36";
+///     Format::Hsv(Hsv::V),                          "0.76";
+///     Format::Hwb(Hwb::H),                          "196.29";
+///     Format::Hwb(Hwb::W),                          "0.49";
+///     Format::Hwb(Hwb::B),                          "0.24";
+///     Format::Oklab(Oklab::L),                      "0.72";
+///     Format::Oklab(Oklab::A),                      "-0.04";
+///     Format::Oklab(Oklab::B),                      "-0.04";
+///     Format::Oklch(Oklch::L),                      "0.72";
+///     Format::Oklch(Oklch::C),                      "0.06";
+///     Format::Oklch(Oklch::H),                      "223.1";
+///     Format::Oklch(Oklch::Function),               "oklch(0.72 0.06 223.1)";
+///     Format::Lab(Lab::L),                          "68.63";
+///     Format::Lab(Lab::A),                          "-12.04";
+///     Format::Lab(Lab::B),                          "-15.05";
+///     Format::Lch(Lch::L),                          "68.63";
+///     Format::Lch(Lch::C),                          "19.27";
+///     Format::Lch(Lch::H),                          "231.32";
+///     Format::Xyz(Xyz::X),                          "0.33";
+///     Format::Xyz(Xyz::Y),                          "0.39";
+///     Format::Xyz(Xyz::Z),                          "0.57";
+///     Format::Ansi256,                              "109";
+///     Format::Ansi16,                               "6";
+///     Format::Packed(Packed::Rgb, Radix::Dec),      "8171458";
+///     Format::Packed(Packed::Rgb, Radix::Hex),      "0x7CAFC2";
+///     Format::Packed(Packed::Argb32, Radix::Dec),   "2155655106";
+///     Format::Packed(Packed::Argb32, Radix::Hex),   "0x807CAFC2";
+///     Format::Packed(Packed::Colorref, Radix::Dec), "12758908";
+///     Format::Packed(Packed::Colorref, Radix::Hex), "0x00C2AF7C";
+///     Format::Css(Css::Hex),                        "#7cafc2";
+///     Format::Css(Css::Hexa),                       "#7cafc280";
+///     Format::Css(Css::Rgb),                        "rgb(124 175 194)";
+///     Format::Css(Css::Rgba),                       "rgb(124 175 194 / 0.5)";
+///     Format::Css(Css::Hsl),                        "hsl(196.29 36.46% 62.35%)";
+///     Format::Css(Css::Hsla),                       "hsl(196.29 36.46% 62.35% / 0.5)";
 /// }
+///
+/// let formatter = |format, precision, percent| {
+///     RgbColorFormatter {
+///         color: color.color,
+///         alpha: color.alpha,
+///         format,
+///         number_format: NumberFormat { precision, percent },
+///     }
+///     .to_string()
+/// };
+///
+/// assert_eq!(formatter(Format::Dec(Dec::R), Some(6), false), "0.486275");
+/// assert_eq!(formatter(Format::Hsl(Hsl::H), Some(0), false), "196");
+/// assert_eq!(formatter(Format::Hsl(Hsl::S), None, true), "36.46");
+/// assert_eq!(formatter(Format::Hsl(Hsl::S), Some(0), true), "36");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RgbColorFormatter {
     pub color: RgbColor,
+    pub alpha: u8,
     pub format: Format,
+    pub number_format: NumberFormat,
 }
 
 impl Display for RgbColorFormatter {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let RgbColor([r, g, b]) = self.color;
+        let a = self.alpha;
 
         match self.format {
-            Format::Hex(Hex::Rgb) => write!(f, "{r:02x}{g:02x}{b:02x}"),
-            Format::Hex(Hex::R) => write!(f, "{r:02x}"),
-            Format::Hex(Hex::G) => write!(f, "{g:02x}"),
-            Format::Hex(Hex::B) => write!(f, "{b:02x}"),
-            Format::Hex(Hex::Bgr) => write!(f, "{b:02x}{g:02x}{r:02x}"),
+            Format::Hex(hex, style) => {
+                let bytes: &[u8] = match hex {
+                    Hex::Rgb => &[r, g, b],
+                    Hex::R => &[r],
+                    Hex::G => &[g],
+                    Hex::B => &[b],
+                    Hex::Bgr => &[b, g, r],
+                    Hex::A => &[a],
+                    Hex::Rgba => &[r, g, b, a],
+                    Hex::Argb => &[a, r, g, b],
+                };
+
+                f.write_str(style.prefix.as_str())?;
+                for byte in bytes {
+                    match style.uppercase {
+                        true => write!(f, "{byte:02X}")?,
+                        false => write!(f, "{byte:02x}")?,
+                    }
+                }
+                Ok(())
+            }
 
             Format::Rgb(Rgb::R) => write!(f, "{r}"),
             Format::Rgb(Rgb::G) => write!(f, "{g}"),
             Format::Rgb(Rgb::B) => write!(f, "{b}"),
+            Format::Rgb(Rgb::A) => write!(f, "{a}"),
+
+            Format::Rgb16(Rgb16::R) => write!(f, "{}", r as u16 * 257),
+            Format::Rgb16(Rgb16::G) => write!(f, "{}", g as u16 * 257),
+            Format::Rgb16(Rgb16::B) => write!(f, "{}", b as u16 * 257),
+            Format::Rgb16(Rgb16::A) => write!(f, "{}", a as u16 * 257),
+
+            Format::Xrgb => write!(f, "rgb:{r:02x}{r:02x}/{g:02x}{g:02x}/{b:02x}{b:02x}"),
+
+            Format::Dec(Dec::R) => self.fraction(f, r as f64 / 255.0, Some(2)),
+            Format::Dec(Dec::G) => self.fraction(f, g as f64 / 255.0, Some(2)),
+            Format::Dec(Dec::B) => self.fraction(f, b as f64 / 255.0, Some(2)),
+            Format::Dec(Dec::A) => self.fraction(f, a as f64 / 255.0, Some(2)),
+
+            Format::Float(Float::R) => self.fraction(f, r as f64 / 255.0, None),
+            Format::Float(Float::G) => self.fraction(f, g as f64 / 255.0, None),
+            Format::Float(Float::B) => self.fraction(f, b as f64 / 255.0, None),
+            Format::Float(Float::A) => self.fraction(f, a as f64 / 255.0, None),
+
+            Format::Hsl(component) => {
+                let HslFormatter {
+                    hue,
+                    luminance,
+                    saturation,
+                } = HslFormatter::from_color(self.color);
+                match component {
+                    Hsl::H => self.number(f, hue, Some(2)),
+                    Hsl::S => self.fraction(f, saturation, Some(2)),
+                    Hsl::L => self.fraction(f, luminance, Some(2)),
+                }
+            }
+            Format::Hsv(component) => {
+                let HsvFormatter {
+                    hue,
+                    saturation,
+                    value,
+                } = HsvFormatter::from_color(self.color);
+                match component {
+                    Hsv::H => self.number(f, hue, Some(2)),
+                    Hsv::S => self.fraction(f, saturation, Some(2)),
+                    Hsv::V => self.fraction(f, value, Some(2)),
+                }
+            }
+            Format::Hwb(component) => {
+                let HwbFormatter {
+                    hue,
+                    whiteness,
+                    blackness,
+                } = HwbFormatter::from_color(self.color);
+                match component {
+                    Hwb::H => self.number(f, hue, Some(2)),
+                    Hwb::W => self.fraction(f, whiteness, Some(2)),
+                    Hwb::B => self.fraction(f, blackness, Some(2)),
+                }
+            }
+
+            Format::Oklab(component) => {
+                let OklabFormatter { lightness, a, b } = OklabFormatter::from_color(self.color);
+                match component {
+                    Oklab::L => self.fraction(f, lightness, Some(2)),
+                    Oklab::A => self.number(f, a, Some(2)),
+                    Oklab::B => self.number(f, b, Some(2)),
+                }
+            }
+            Format::Oklch(component) => {
+                let OklchFormatter {
+                    lightness,
+                    chroma,
+                    hue,
+                } = OklchFormatter::from_color(self.color);
+                match component {
+                    Oklch::L => self.fraction(f, lightness, Some(2)),
+                    Oklch::C => self.number(f, chroma, Some(2)),
+                    Oklch::H => self.number(f, hue, Some(1)),
+                    Oklch::Function => write!(f, "oklch({lightness:.2} {chroma:.2} {hue:.1})"),
+                }
+            }
+
+            Format::Lab(component) => {
+                let LabFormatter { lightness, a, b } = LabFormatter::from_color(self.color);
+                match component {
+                    Lab::L => self.number(f, lightness, Some(2)),
+                    Lab::A => self.number(f, a, Some(2)),
+                    Lab::B => self.number(f, b, Some(2)),
+                }
+            }
+            Format::Lch(component) => {
+                let LchFormatter {
+                    lightness,
+                    chroma,
+                    hue,
+                } = LchFormatter::from_color(self.color);
+                match component {
+                    Lch::L => self.number(f, lightness, Some(2)),
+                    Lch::C => self.number(f, chroma, Some(2)),
+                    Lch::H => self.number(f, hue, Some(2)),
+                }
+            }
+            Format::Xyz(component) => {
+                let XyzFormatter { x, y, z } = XyzFormatter::from_color(self.color);
+                match component {
+                    Xyz::X => self.fraction(f, x, Some(2)),
+                    Xyz::Y => self.fraction(f, y, Some(2)),
+                    Xyz::Z => self.fraction(f, z, Some(2)),
+                }
+            }
 
-            Format::Dec(Dec::R) => write!(f, "{:.2}", r as f64 / 255.0),
-            Format::Dec(Dec::G) => write!(f, "{:.2}", g as f64 / 255.0),
-            Format::Dec(Dec::B) => write!(f, "{:.2}", b as f64 / 255.0),
+            Format::Ansi256 => write!(f, "{}", AnsiFormatter::nearest_ansi256(self.color)),
+            Format::Ansi16 => write!(f, "{}", AnsiFormatter::nearest_ansi16(self.color)),
 
-            Format::Hsl(Hsl::H) => write!(f, "{:.2}", HslFormatter::from_color(self.color).hue),
-            Format::Hsl(Hsl::L) => {
-                write!(f, "{:.2}", HslFormatter::from_color(self.color).luminance)
+            Format::Packed(packed, Radix::Dec) => write!(f, "{}", packed.pack(self.rgba())),
+            Format::Packed(Packed::Rgb, Radix::Hex) => {
+                write!(f, "0x{:06X}", Packed::Rgb.pack(self.rgba()))
             }
-            Format::Hsl(Hsl::S) => {
-                write!(f, "{:.2}", HslFormatter::from_color(self.color).saturation)
+            Format::Packed(packed, Radix::Hex) => write!(f, "0x{:08X}", packed.pack(self.rgba())),
+
+            Format::Css(Css::Hex) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            Format::Css(Css::Hexa) => write!(f, "#{r:02x}{g:02x}{b:02x}{a:02x}"),
+            Format::Css(Css::Rgb) => write!(f, "rgb({r} {g} {b})"),
+            Format::Css(Css::Rgba) => write!(f, "rgb({r} {g} {b} / {})", css_alpha(a)),
+            Format::Css(css @ (Css::Hsl | Css::Hsla)) => {
+                let HslFormatter {
+                    hue,
+                    luminance,
+                    saturation,
+                } = HslFormatter::from_color(self.color);
+                let [saturation, luminance] = [saturation, luminance].map(|value| value * 100.0);
+
+                write!(f, "hsl({hue:.2} {saturation:.2}% {luminance:.2}%")?;
+                if css == Css::Hsla {
+                    write!(f, " / {}", css_alpha(a))?;
+                }
+                f.write_str(")")
             }
         }
     }
 }
 
+impl RgbColorFormatter {
+    /// [`Self::color`] together with [`Self::alpha`]
+    pub fn rgba(&self) -> RgbaColor {
+        self.color.with_alpha(self.alpha)
+    }
+
+    /// Write `value` with the precision of [`Self::number_format`],
+    /// or `default_precision` if it doesn't specify one. (`None` means full precision)
+    fn number(
+        &self,
+        f: &mut Formatter<'_>,
+        value: f64,
+        default_precision: Option<usize>,
+    ) -> fmt::Result {
+        match self
+            .number_format
+            .precision
+            .map(usize::from)
+            .or(default_precision)
+        {
+            Some(precision) => write!(f, "{value:.precision$}"),
+            None => write!(f, "{value}"),
+        }
+    }
+
+    /// Write `value`, which lies between 0 and 1, like [`Self::number`],
+    /// but as percentage if [`NumberFormat::percent`] is set.
+    fn fraction(
+        &self,
+        f: &mut Formatter<'_>,
+        value: f64,
+        default_precision: Option<usize>,
+    ) -> fmt::Result {
+        match self.number_format.percent {
+            true => self.number(f, value * 100.0, default_precision),
+            false => self.number(f, value, default_precision),
+        }
+    }
+}
+
+/// Turn `alpha` into a number between 0 and 1 with as few decimals as possible,
+/// as specified by [CSS Color 4](https://www.w3.org/TR/css-color-4/#serializing-alpha-values).
+fn css_alpha(alpha: u8) -> f64 {
+    let alpha = alpha as f64 / 255.0;
+    let rounded = (alpha * 100.0).round() / 100.0;
+    match (rounded * 255.0).round() == (alpha * 255.0).round() {
+        true => rounded,
+        false => (alpha * 1000.0).round() / 1000.0,
+    }
+}
+
 impl Content for RgbColorFormatter {
     fn is_truthy(&self) -> bool {
         true
@@ -100,4 +375,36 @@ impl Content for RgbColorFormatter {
     fn render_escaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
         encoder.format_escaped(self)
     }
+
+    fn render_unescaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        encoder.format_unescaped(self)
+    }
+
+    /// Render the color in the format `name`, which is a color field without the
+    /// `base<index>-` prefix, e.g. `hex` or `lighten-10-rgb-r`.
+    ///
+    /// This is used inside of color sections like `{{#base08-hex}}{{rgb-r}}{{/base08-hex}}`.
+    /// Mixing and readable foregrounds need the scheme and are not supported here.
+    fn render_field_escaped<E: Encoder>(
+        &self,
+        _hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        let Ok((modifier, format, number_format)) = parse_section_field(name) else {
+            return Ok(false);
+        };
+
+        let color = match modifier {
+            Some(modifier) => modifier.apply(self.color),
+            None => self.color,
+        };
+        let formatter = RgbColorFormatter {
+            color,
+            alpha: self.alpha,
+            format,
+            number_format,
+        };
+        encoder.format_escaped(formatter).map(|_| true)
+    }
 }
@@ -0,0 +1,139 @@
+use crate::{
+    convert,
+    scheme::{RgbColorError, RgbaColor},
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, multispace0, multispace1},
+    combinator::{all_consuming, opt, success, verify},
+    number::complete::double,
+    sequence::{delimited, preceded, tuple},
+    Finish, IResult, Parser,
+};
+
+/// argument of a CSS color function
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Argument {
+    Number(f64),
+    Percentage(f64),
+    /// angle in degrees
+    Angle(f64),
+}
+
+impl Argument {
+    /// Resolve the argument as a number, where `100%` is `hundred_percent`.
+    fn number(self, hundred_percent: f64) -> Result<f64, RgbColorError> {
+        match self {
+            Self::Number(number) => Ok(number),
+            Self::Percentage(percentage) => Ok(percentage / 100.0 * hundred_percent),
+            Self::Angle(_) => Err(RgbColorError::InvalidFunction),
+        }
+    }
+
+    /// Resolve the argument as a hue in degrees.
+    fn hue(self) -> Result<f64, RgbColorError> {
+        match self {
+            Self::Number(degrees) | Self::Angle(degrees) => Ok(degrees),
+            Self::Percentage(_) => Err(RgbColorError::InvalidFunction),
+        }
+    }
+}
+
+/// Parse a color written in CSS functional notation.
+///
+/// see [`RgbaColor::from_str`](std::str::FromStr::from_str)
+pub(super) fn from_functional(input: &str) -> Result<RgbaColor, RgbColorError> {
+    use convert::{
+        clip, clip_channel, from_linear, hsl_to_srgb, hwb_to_srgb, lab_to_xyz,
+        oklab_to_linear_srgb, polar_to_rectangular, xyz_d50_to_d65, xyz_to_linear_srgb, D50,
+    };
+
+    let (_, (name, [a, b, c], alpha)) = all_consuming(function)(input)
+        .finish()
+        .map_err(|_| RgbColorError::InvalidFunction)?;
+
+    let from_linear_srgb = |linear: [f64; 3]| linear.map(from_linear);
+    let lab_to_srgb =
+        |lab| from_linear_srgb(xyz_to_linear_srgb(xyz_d50_to_d65(lab_to_xyz(lab, D50))));
+
+    let srgb = match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => [a.number(255.0)?, b.number(255.0)?, c.number(255.0)?].map(|c| c / 255.0),
+        "hsl" | "hsla" => {
+            hsl_to_srgb([a.hue()?, b.number(100.0)? / 100.0, c.number(100.0)? / 100.0])
+        }
+        "hwb" => hwb_to_srgb([a.hue()?, b.number(100.0)? / 100.0, c.number(100.0)? / 100.0]),
+        "lab" => lab_to_srgb([a.number(100.0)?, b.number(125.0)?, c.number(125.0)?]),
+        "lch" => lab_to_srgb(polar_to_rectangular([
+            a.number(100.0)?,
+            b.number(150.0)?,
+            c.hue()?,
+        ])),
+        "oklab" => from_linear_srgb(oklab_to_linear_srgb([
+            a.number(1.0)?,
+            b.number(0.4)?,
+            c.number(0.4)?,
+        ])),
+        "oklch" => from_linear_srgb(oklab_to_linear_srgb(polar_to_rectangular([
+            a.number(1.0)?,
+            b.number(0.4)?,
+            c.hue()?,
+        ]))),
+        _ => return Err(RgbColorError::UnknownFunction),
+    };
+
+    let alpha = match alpha {
+        Some(alpha) => alpha.number(1.0)?,
+        None => 1.0,
+    };
+
+    Ok(clip(srgb).with_alpha(clip_channel(alpha)))
+}
+
+fn function(input: &str) -> IResult<&str, (&str, [Argument; 3], Option<Argument>)> {
+    tuple((
+        alpha1,
+        delimited(multispace0, tag("("), multispace0),
+        argument,
+        separator,
+        argument,
+        separator,
+        argument,
+        opt(preceded(
+            alt((
+                delimited(multispace0, tag("/"), multispace0),
+                delimited(multispace0, tag(","), multispace0),
+            )),
+            argument,
+        )),
+        delimited(multispace0, tag(")"), multispace0),
+    ))
+    .map(|(name, _, a, _, b, _, c, alpha, _)| (name, [a, b, c], alpha))
+    .parse(input)
+}
+
+fn separator(input: &str) -> IResult<&str, &str> {
+    alt((delimited(multispace0, tag(","), multispace0), multispace1))(input)
+}
+
+/// number with an optional unit, values that aren't finite (`nan`, `inf`, `1e308turn`) are
+/// rejected
+fn argument(input: &str) -> IResult<&str, Argument> {
+    let (input, value) = double(input)?;
+
+    verify(
+        alt((
+            tag("%").map(move |_| Argument::Percentage(value)),
+            tag("deg").map(move |_| Argument::Angle(value)),
+            tag("grad").map(move |_| Argument::Angle(value * 0.9)),
+            tag("rad").map(move |_| Argument::Angle(value.to_degrees())),
+            tag("turn").map(move |_| Argument::Angle(value * 360.0)),
+            success(Argument::Number(value)),
+        )),
+        |argument| match *argument {
+            Argument::Number(value) | Argument::Percentage(value) | Argument::Angle(value) => {
+                value.is_finite()
+            }
+        },
+    )(input)
+}
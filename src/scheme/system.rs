@@ -0,0 +1,140 @@
+use crate::scheme::BaseIndex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeSet,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::RangeInclusive,
+};
+
+/// type representing the system a [`Scheme`](crate::Scheme) belongs to
+///
+/// - [`System::Base16`] uses `base00` to `base0F`
+/// - [`System::Base24`] additionally uses `base10` to `base17`
+/// - [`System::Custom`] is any other system, it is never detected but only declared
+///
+/// It gets serialized as `base16`, `base24` or the name of the custom system.
+/// When deserializing any unknown system is kept as [`System::Custom`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base16_color_scheme::scheme::{BaseIndex, System};
+///
+/// assert_eq!(System::from_indices([BaseIndex(0x00), BaseIndex(0x0F)]), System::Base16);
+/// assert_eq!(System::from_indices([BaseIndex(0x00), BaseIndex(0x17)]), System::Base24);
+/// // slots outside of base24 don't change the system, they are reported by `Scheme::validate`
+/// assert_eq!(System::from_indices([BaseIndex(0x00), BaseIndex(0x18)]), System::Base16);
+/// assert_eq!(System::from_indices([BaseIndex(0x10), BaseIndex(0x18)]), System::Base24);
+/// assert_eq!(System::Base24.to_string(), "base24");
+///
+/// let system: System = serde_yaml::from_str("base32").unwrap();
+/// assert_eq!(system, System::Custom("base32".to_owned()));
+/// assert_eq!(serde_yaml::to_string(&system).unwrap(), "base32\n");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum System {
+    #[default]
+    Base16,
+    Base24,
+    /// any other system, with its name
+    Custom(String),
+}
+
+impl System {
+    /// Detect the system of a scheme or template using the colors at `indices`.
+    ///
+    /// If any of `base10` to `base17` is used it is [`System::Base24`],
+    /// otherwise [`System::Base16`].
+    pub fn from_indices<I>(indices: I) -> Self
+    where
+        I: IntoIterator<Item = BaseIndex>,
+    {
+        match indices
+            .into_iter()
+            .any(|BaseIndex(index)| (0x10..=0x17).contains(&index))
+        {
+            true => Self::Base24,
+            false => Self::Base16,
+        }
+    }
+
+    /// Indices a scheme of this system is expected to define.
+    ///
+    /// [`System::Custom`] doesn't define any indices, so this returns [`None`].
+    pub fn indices(&self) -> Option<RangeInclusive<u8>> {
+        match self {
+            Self::Base16 => Some(0x00..=0x0F),
+            Self::Base24 => Some(0x00..=0x17),
+            Self::Custom(_) => None,
+        }
+    }
+
+    /// Name of the system as used by the `{{scheme-system}}` tag.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Base16 => "base16",
+            Self::Base24 => "base24",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl Serialize for System {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for System {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        Ok(match name.as_str() {
+            "base16" => Self::Base16,
+            "base24" => Self::Base24,
+            _ => Self::Custom(name),
+        })
+    }
+}
+
+impl Display for System {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned by [`Scheme::render_checked`](crate::Scheme::render_checked)
+///
+/// It is returned if the template uses colors the scheme doesn't define,
+/// for example when rendering a base24 template with a base16 scheme.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SystemMismatch {
+    /// system of the scheme that was rendered
+    pub scheme: System,
+    /// system detected from the colors the template uses
+    pub template: System,
+    /// colors used by the template that the scheme doesn't define
+    pub missing: BTreeSet<BaseIndex>,
+}
+
+impl Display for SystemMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} template used with {} scheme, missing colors:",
+            self.template, self.scheme
+        )?;
+        for BaseIndex(index) in &self.missing {
+            write!(f, " base{index:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for SystemMismatch {}
@@ -0,0 +1,196 @@
+use crate::scheme::{BaseIndex, RgbaColor, Scheme, Variant};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display, Formatter},
+};
+
+/// report returned by [`Scheme::validate`]
+///
+/// Missing or unexpected slots and an empty name or author are considered errors,
+/// see [`ValidationReport::is_valid`].
+///
+/// Duplicate colors and base00 to base07 not being ordered by lightness are considered warnings,
+/// see [`ValidationReport::has_warnings`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base16_color_scheme::{
+///     scheme::{BaseIndex, RgbColor},
+///     Scheme,
+/// };
+/// use std::collections::{BTreeMap, BTreeSet};
+///
+/// let scheme: Scheme = serde_yaml::from_str(r#"
+/// scheme: "Scheme Name"
+/// author: ""
+/// base00: "181818"
+/// base01: "f8f8f8"
+/// base02: "383838"
+/// base03: "383838"
+/// "#).unwrap();
+///
+/// let report = scheme.validate();
+/// assert!(!report.is_valid());
+/// assert!(report.has_warnings());
+///
+/// assert_eq!(report.missing, (0x04..=0x0F).map(BaseIndex).collect());
+/// assert!(report.extra.is_empty());
+/// assert_eq!(
+///     report.duplicates,
+///     BTreeMap::from([(
+///         RgbColor([0x38, 0x38, 0x38]).into(),
+///         BTreeSet::from([BaseIndex(0x02), BaseIndex(0x03)])
+///     )])
+/// );
+/// assert!(!report.empty_name);
+/// assert!(report.empty_author);
+/// assert_eq!(report.lightness_inversions, [(BaseIndex(0x01), BaseIndex(0x02))]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ValidationReport {
+    /// slots required by the system that are not defined, see [`Scheme::validate`]
+    pub missing: BTreeSet<BaseIndex>,
+    /// slots that are defined but not part of the system
+    pub extra: BTreeSet<BaseIndex>,
+    /// colors that are used by more than one slot, including their [alpha](Scheme::alpha)
+    pub duplicates: BTreeMap<RgbaColor, BTreeSet<BaseIndex>>,
+    /// [`Scheme::scheme`] is empty
+    pub empty_name: bool,
+    /// [`Scheme::author`] is empty
+    pub empty_author: bool,
+    /// neighbouring slots between `base00` and `base07` which are not ordered from darkest
+    /// to lightest (or lightest to darkest for a [light](Variant::Light) scheme)
+    pub lightness_inversions: Vec<(BaseIndex, BaseIndex)>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if the report doesn't contain any errors.
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && !self.empty_name && !self.empty_author
+    }
+
+    /// Returns `true` if the report contains any warnings.
+    pub fn has_warnings(&self) -> bool {
+        !self.duplicates.is_empty() || !self.lightness_inversions.is_empty()
+    }
+}
+
+impl Scheme {
+    /// Check the scheme for common mistakes.
+    ///
+    /// The slots are checked against the [system of the scheme](Scheme::scheme_system).
+    ///
+    /// see [`ValidationReport`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::{
+    ///     scheme::{BaseIndex, RgbColor, System},
+    ///     Scheme,
+    /// };
+    /// use std::collections::BTreeSet;
+    ///
+    /// // a stray slot is reported, it doesn't change the system
+    /// let scheme = Scheme {
+    ///     colors: (0x00..=0x0F)
+    ///         .chain([0x18])
+    ///         .map(|index| (BaseIndex(index), RgbColor([index, index, index])))
+    ///         .collect(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let report = scheme.validate();
+    /// assert!(report.missing.is_empty());
+    /// assert_eq!(report.extra, BTreeSet::from([BaseIndex(0x18)]));
+    /// assert_eq!(scheme.scheme_system(), System::Base16);
+    ///
+    /// // the same color with a different alpha isn't a duplicate
+    /// let scheme: Scheme = serde_yaml::from_str(r##"
+    /// scheme: "Scheme Name"
+    /// author: "Scheme Author"
+    /// base00: "#383838"
+    /// base01: "#38383880"
+    /// "##).unwrap();
+    /// assert!(scheme.validate().duplicates.is_empty());
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        let (missing, extra) = match self.scheme_system().indices() {
+            Some(indices) => (
+                indices
+                    .clone()
+                    .map(BaseIndex)
+                    .filter(|index| !self.colors.contains_key(index))
+                    .collect(),
+                self.colors
+                    .keys()
+                    .filter(|BaseIndex(index)| !indices.contains(index))
+                    .copied()
+                    .collect(),
+            ),
+            None => Default::default(),
+        };
+
+        let mut duplicates = BTreeMap::<_, BTreeSet<_>>::new();
+        for (index, color) in self.rgba_colors() {
+            duplicates.entry(color).or_default().insert(index);
+        }
+        duplicates.retain(|_, indices| indices.len() > 1);
+
+        let light = self.scheme_variant() == Some(Variant::Light);
+        let shades: Vec<_> = self
+            .colors
+            .range(BaseIndex(0x00)..=BaseIndex(0x07))
+            .map(|(&index, color)| (index, color.relative_luminance()))
+            .collect();
+        let lightness_inversions = shades
+            .windows(2)
+            .filter(|pair| match light {
+                true => pair[0].1 < pair[1].1,
+                false => pair[0].1 > pair[1].1,
+            })
+            .map(|pair| (pair[0].0, pair[1].0))
+            .collect();
+
+        ValidationReport {
+            missing,
+            extra,
+            duplicates,
+            empty_name: self.scheme.is_empty(),
+            empty_author: self.author.is_empty(),
+            lightness_inversions,
+        }
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.empty_name {
+            writeln!(f, "error: scheme name is empty")?;
+        }
+        if self.empty_author {
+            writeln!(f, "error: scheme author is empty")?;
+        }
+        for BaseIndex(index) in &self.missing {
+            writeln!(f, "error: missing base{index:02X}")?;
+        }
+        for BaseIndex(index) in &self.extra {
+            writeln!(f, "error: unexpected base{index:02X}")?;
+        }
+        for (color, indices) in &self.duplicates {
+            write!(f, "warning: {color} is used by")?;
+            for BaseIndex(index) in indices {
+                write!(f, " base{index:02X}")?;
+            }
+            writeln!(f)?;
+        }
+        for (BaseIndex(previous), BaseIndex(next)) in &self.lightness_inversions {
+            writeln!(
+                f,
+                "warning: base{previous:02X} and base{next:02X} are not ordered by lightness"
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::scheme::RgbColor;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// type representing whether a [`Scheme`](crate::Scheme) is light or dark
+///
+/// # Examples
+///
+/// ```rust
+/// use base16_color_scheme::scheme::Variant;
+///
+/// let variant: Variant = serde_yaml::from_str("light").unwrap();
+/// assert_eq!(variant, Variant::Light);
+/// assert_eq!(variant.to_string(), "light");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Variant {
+    Light,
+    Dark,
+}
+
+impl Variant {
+    /// Detect the variant by comparing the [relative luminance](RgbColor::relative_luminance)
+    /// of the `background` (`base00`) and `foreground` (`base07`) color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use base16_color_scheme::scheme::{RgbColor, Variant};
+    ///
+    /// let black = RgbColor([0x18, 0x18, 0x18]);
+    /// let white = RgbColor([0xf8, 0xf8, 0xf8]);
+    ///
+    /// assert_eq!(Variant::detect(black, white), Variant::Dark);
+    /// assert_eq!(Variant::detect(white, black), Variant::Light);
+    /// ```
+    pub fn detect(background: RgbColor, foreground: RgbColor) -> Self {
+        if background.relative_luminance() > foreground.relative_luminance() {
+            Self::Light
+        } else {
+            Self::Dark
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+impl Display for Variant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -2,6 +2,7 @@ use color_field::ColorField;
 use serde::{Deserialize, Serialize};
 
 pub mod color_field;
+pub mod extension;
 
 /// type representing a field in the mustache template
 ///
@@ -11,7 +12,7 @@ pub mod color_field;
 ///
 /// ```rust
 /// use base16_color_scheme::template::{
-///     color_field::{ColorField, Format, Hex},
+///     color_field::{ColorField, Format, Hex, HexStyle, NumberFormat},
 ///     TemplateField,
 /// };
 ///
@@ -28,21 +29,47 @@ pub mod color_field;
 ///     TemplateField::SchemeSlug
 /// );
 /// assert_eq!(
+///     TemplateField::parse_field("scheme-system"),
+///     TemplateField::SchemeSystem
+/// );
+/// assert_eq!(
+///     TemplateField::parse_field("scheme-meta-license"),
+///     TemplateField::SchemeMeta("license")
+/// );
+/// assert_eq!(
 ///     TemplateField::parse_field("base07-hex-r"),
 ///     TemplateField::ColorField(ColorField {
 ///         number: 0x07,
-///         format: Format::Hex(Hex::R)
+///         mix: None,
+///         modifier: None,
+///         foreground: None,
+///         format: Format::Hex(Hex::R, HexStyle::default()),
+///         number_format: NumberFormat::default(),
 ///     })
 /// )
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum TemplateField {
+pub enum TemplateField<'a> {
     /// this variant is returned for `scheme-name` and `scheme`
     SchemeName,
     /// this variant is returned for `scheme-author`
     SchemeAuthor,
     /// this variant is returned for `scheme-slug`
     SchemeSlug,
+    /// this variant is returned for `scheme-system`
+    SchemeSystem,
+    /// this variant is returned for `scheme-variant`
+    SchemeVariant,
+    /// this variant is returned for `scheme-is-light-variant`
+    SchemeIsLightVariant,
+    /// this variant is returned for `scheme-is-dark-variant`
+    SchemeIsDarkVariant,
+    /// this variant is returned for `scheme-description`
+    SchemeDescription,
+    /// this variant is returned for `scheme-meta-<key>` and contains `<key>`
+    ///
+    /// see [`Scheme::metadata`](crate::Scheme::metadata)
+    SchemeMeta(&'a str),
     /// this variant is returned for a color description
     ///
     /// see [`ColorField`](color_field::ColorField) and [`Format`](color_field::Format) for details
@@ -51,12 +78,20 @@ pub enum TemplateField {
     UnparsableField,
 }
 
-impl TemplateField {
-    pub fn parse_field(name: &str) -> Self {
+impl<'a> TemplateField<'a> {
+    pub fn parse_field(name: &'a str) -> Self {
         match name {
             "scheme-name" | "scheme" => Self::SchemeName,
             "scheme-author" => Self::SchemeAuthor,
             "scheme-slug" => Self::SchemeSlug,
+            "scheme-system" => Self::SchemeSystem,
+            "scheme-variant" => Self::SchemeVariant,
+            "scheme-is-light-variant" => Self::SchemeIsLightVariant,
+            "scheme-is-dark-variant" => Self::SchemeIsDarkVariant,
+            "scheme-description" => Self::SchemeDescription,
+            _ if name.starts_with("scheme-meta-") => {
+                Self::SchemeMeta(&name["scheme-meta-".len()..])
+            }
             _ => {
                 if let Ok(field_spec) = name.parse() {
                     Self::ColorField(field_spec)
@@ -1,17 +1,43 @@
 use hex::FromHexError;
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take},
-    combinator::map_res,
-    sequence::tuple,
+    bytes::complete::take,
+    character::complete::digit1,
+    combinator::{cut, map_opt, map_res, opt},
+    error::{ErrorKind, FromExternalError, ParseError},
+    sequence::{preceded, terminated, tuple},
     Finish, IResult, Parser,
 };
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
 
-pub use self::format::{hsl::HslFormatter, Dec, Format, Hex, Hsl, Rgb};
+pub use self::format::{
+    ansi::AnsiFormatter,
+    hsl::HslFormatter,
+    hsv::HsvFormatter,
+    hwb::HwbFormatter,
+    lab::{LabFormatter, LchFormatter, XyzFormatter},
+    oklab::{OklabFormatter, OklchFormatter},
+    Css, Dec, Float, Format, Hex, HexPrefix, HexStyle, Hsl, Hsv, Hwb, Lab, Lch, NumberFormat,
+    Oklab, Oklch, Packed, Radix, Rgb, Rgb16, Xyz,
+};
 
+pub use self::{
+    contrast::ContrastLevel,
+    mix::{Mix, MixSpace},
+    modifier::Modifier,
+};
+
+mod contrast;
 mod format;
+mod mix;
+mod modifier;
 
 /// this represents a field in the template containing a color description defined by the
 /// [specification](https://github.com/chriskempson/base16/blob/main/builder.md#template-tags)
@@ -19,6 +45,30 @@ mod format;
 /// Note that in contrast to the base16 spec this supports up to 256 colors. \
 /// (exactly what fits into a [`u8`])
 ///
+/// A [`Modifier`] can be put between the index and the format,
+/// e.g. `base01-lighten-10-hex`, `base08-darken-20-rgb-r` or `base05-rotate-30-hex`.
+///
+/// Before the modifier another color of the scheme can be [mixed](Mix) in,
+/// e.g. `base00-mix-base0B-15-hex` or `base00-mix-oklab-base0B-15-lighten-10-hex`.
+///
+/// After that `fg` replaces the color with the most readable color of the scheme on top of it,
+/// optionally followed by the required [contrast level](ContrastLevel),
+/// e.g. `base0D-fg-hex` or `base0D-fg-aaa-hex`.
+///
+/// Formats writing a floating point number can be followed by `-p<decimals>` or `-int`
+/// to set the [precision](NumberFormat), e.g. `base08-dec-r-p6` or `base08-hsl-h-int`.
+/// Components that are a fraction between 0 and 1 can additionally be written as percentage
+/// with `-pct` before the precision, e.g. `base08-hsl-s-pct` or `base08-oklch-l-pct-p1`.
+/// Hues, chroma and the components of Lab, LCh and the a/b of OKLab aren't fractions,
+/// so `base08-hsl-h-pct` is an error.
+///
+/// Hex formats can additionally be followed by a prefix (`-hash` for `#`, `-0x` for `0x`)
+/// and `-upper` for uppercase digits, e.g. `base08-hex-hash`, `base08-hex-bgr-0x-upper`.
+///
+/// Fields that don't use a built-in format can be rendered by the
+/// [extensions](super::extension::FormatExtension) of a
+/// [`RenderContext`](super::extension::RenderContext).
+///
 /// This is used in combination with the [`Scheme`](crate::Scheme) to look up the specified color.\
 /// See [`Scheme::color()`](crate::Scheme::color)
 ///
@@ -27,7 +77,9 @@ mod format;
 /// ```rust
 /// use base16_color_scheme::{
 ///     scheme::{BaseIndex, RgbColor, RgbColorFormatter},
-///     template::color_field::{ColorField, Format, Hex},
+///     template::color_field::{
+///         ColorField, Format, Hex, HexPrefix, HexStyle, Mix, MixSpace, Modifier, NumberFormat,
+///     },
 ///     Scheme,
 /// };
 /// use std::collections::BTreeMap;
@@ -42,33 +94,170 @@ mod format;
 ///     scheme.color(color_field),
 ///     Some(RgbColorFormatter {
 ///         color: RgbColor([0x7c, 0xaf, 0xc2]),
-///         format: Format::Hex(Hex::R)
+///         alpha: 0xff,
+///         format: Format::Hex(Hex::R, HexStyle::default()),
+///         number_format: NumberFormat::default(),
 ///     })
-/// )
+/// );
+///
+/// let color_field: ColorField = "base07-hex-hash-upper".parse().unwrap();
+/// assert_eq!(
+///     scheme.color(color_field).unwrap().to_string(),
+///     "#7CAFC2"
+/// );
+///
+/// let color_field: ColorField = "base07-hex-bgr-0x".parse().unwrap();
+/// assert_eq!(
+///     color_field.format,
+///     Format::Hex(Hex::Bgr, HexStyle { prefix: HexPrefix::ZeroX, uppercase: false })
+/// );
+///
+/// let color_field: ColorField = "base07-lighten-10-hex".parse().unwrap();
+/// assert_eq!(color_field.modifier, Some(Modifier::Lighten(10)));
+/// assert_eq!(scheme.color(color_field).unwrap().to_string(), "9fc4d2");
+///
+/// let color_field: ColorField = "base07-mix-oklab-base07-50-hex".parse().unwrap();
+/// assert_eq!(
+///     color_field.mix,
+///     Some(Mix { with: 0x07, amount: 50, space: MixSpace::Oklab })
+/// );
+/// assert_eq!(scheme.color(color_field).unwrap().to_string(), "7cafc2");
+///
+/// let color_field: ColorField = "base07-hsl-s-pct-p1".parse().unwrap();
+/// assert_eq!(
+///     color_field.number_format,
+///     NumberFormat { precision: Some(1), percent: true }
+/// );
+/// assert_eq!(scheme.color(color_field).unwrap().to_string(), "36.5");
+///
+/// // formats that don't write a floating point number don't take a number format
+/// assert!("base07-rgb-r-p2".parse::<ColorField>().is_err());
+/// assert!("base07-hex-pct".parse::<ColorField>().is_err());
+/// assert!("base07-oklch-int".parse::<ColorField>().is_err());
+///
+/// // only fractions can be written as percentage
+/// assert!("base07-hsl-h-p0".parse::<ColorField>().is_ok());
+/// assert!("base07-hsl-h-pct".parse::<ColorField>().is_err());
+/// assert!("base07-lab-l-pct".parse::<ColorField>().is_err());
+/// assert!("base07-oklch-h-pct".parse::<ColorField>().is_err());
+/// assert!("base07-oklch-l-pct".parse::<ColorField>().is_ok());
+///
+/// let color_field: ColorField = "base07-xrgb".parse().unwrap();
+/// assert_eq!(
+///     scheme.color(color_field).unwrap().to_string(),
+///     "rgb:7c7c/afaf/c2c2"
+/// );
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct ColorField {
     pub number: u8,
+    /// other color of the scheme that is mixed into the color
+    pub mix: Option<Mix>,
+    /// transformation applied to the (mixed) color before formatting it
+    pub modifier: Option<Modifier>,
+    /// replace the color with a [readable foreground](crate::Scheme::readable_foreground) on it
+    pub foreground: Option<ContrastLevel>,
     pub format: Format,
+    pub number_format: NumberFormat,
 }
 
 impl FromStr for ColorField {
     type Err = ColorFieldError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let (input, (number, format)) = parse_field(input).finish().map_err(|_| ColorFieldError)?;
+        parse_field(input)
+            .finish()
+            .map(|(_, color_field)| color_field)
+            .map_err(|error| error.into_color_field_error(input))
+    }
+}
 
-        if !input.is_empty() {
-            return Err(ColorFieldError);
-        }
+type ParseResult<'a, O> = IResult<&'a str, O, FieldError<'a>>;
 
-        Ok(Self { number, format })
-    }
+fn parse_field(input: &str) -> ParseResult<'_, ColorField> {
+    let (
+        input,
+        (
+            FieldColor {
+                number,
+                mix,
+                modifier,
+                foreground,
+            },
+            (format, number_format),
+        ),
+    ) = tuple((field_color, terminated(format, end)))(input)?;
+    Ok((
+        input,
+        ColorField {
+            number,
+            mix,
+            modifier,
+            foreground,
+            format,
+            number_format,
+        },
+    ))
+}
+
+/// the part of a field that picks the color, which is everything before the format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FieldColor {
+    pub number: u8,
+    pub mix: Option<Mix>,
+    pub modifier: Option<Modifier>,
+    pub foreground: Option<ContrastLevel>,
+}
+
+/// Parse the part of a field before the format and return it together with the format,
+/// e.g. `base08-lighten-10-` and `led` for `base08-lighten-10-led`.
+///
+/// This is used for the formats of [extensions](super::extension::FormatExtension),
+/// which aren't known to the parser.
+pub(crate) fn parse_field_color(input: &str) -> Option<(FieldColor, &str)> {
+    let (format, field_color) = field_color(input).ok()?;
+    Some((field_color, format))
 }
 
-fn parse_field(input: &str) -> IResult<&str, (u8, Format)> {
-    let (input, (_, number, _, format)) = tuple((
-        tag("base"),
+/// `base<index>-[mix-...-][<modifier>-<amount>-][fg-[<level>-]]`
+fn field_color(input: &str) -> ParseResult<'_, FieldColor> {
+    tuple((prefix, opt(mix), opt(modifier), opt(foreground)))
+        .map(|(number, mix, modifier, foreground)| FieldColor {
+            number,
+            mix,
+            modifier,
+            foreground,
+        })
+        .parse(input)
+}
+
+/// Parse the part of a field after the `base<index>-` prefix, without mix and `fg`,
+/// e.g. `hex` or `lighten-10-rgb-r`.
+///
+/// This is what fields inside of color sections look like,
+/// see [`RgbColorFormatter`](crate::scheme::RgbColorFormatter).
+pub(crate) fn parse_section_field(
+    input: &str,
+) -> Result<(Option<Modifier>, Format, NumberFormat), ColorFieldError> {
+    tuple((opt(modifier), terminated(format, end)))(input)
+        .finish()
+        .map(|(_, (modifier, (format, number_format)))| (modifier, format, number_format))
+        .map_err(|error| error.into_color_field_error(input))
+}
+
+/// `base<index>-`
+fn prefix(input: &str) -> ParseResult<'_, u8> {
+    let (input, (_, number, _)) = tuple((
+        kind(ColorFieldErrorKind::Prefix, keyword("base")),
+        index,
+        separator,
+    ))(input)?;
+    Ok((input, number))
+}
+
+fn index(input: &str) -> ParseResult<'_, u8> {
+    kind(
+        ColorFieldErrorKind::Index,
         map_res(take(2usize), |input| -> Result<u8, FromHexError> {
             let mut hex = [0u8];
             hex::decode_to_slice(input, &mut hex)?;
@@ -76,43 +265,608 @@ fn parse_field(input: &str) -> IResult<&str, (u8, Format)> {
 
             Ok(number)
         }),
-        tag("-"),
+    )(input)
+}
+
+fn separator(input: &str) -> ParseResult<'_, &str> {
+    kind(ColorFieldErrorKind::Separator, keyword("-"))(input)
+}
+
+/// Parse an amount and turn it into a value with `constructor`,
+/// which returns `None` if the amount is out of range.
+fn amount<'a, O>(
+    constructor: impl FnMut(u16) -> Option<O>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, O> {
+    kind(
+        ColorFieldErrorKind::Amount,
+        map_opt(map_res(digit1, str::parse), constructor),
+    )
+}
+
+fn percentage(amount: u16) -> Option<u8> {
+    (amount <= 100).then_some(amount as u8)
+}
+
+/// `mix-[<space>-]base<index>-<amount>-`
+fn mix(input: &str) -> ParseResult<'_, Mix> {
+    let (input, (space, with, amount)) = preceded(
+        keyword("mix-"),
+        cut(tuple((
+            opt(terminated(
+                alt((
+                    keyword("srgb").map(|_| MixSpace::Srgb),
+                    keyword("oklab").map(|_| MixSpace::Oklab),
+                )),
+                separator,
+            )),
+            prefix,
+            terminated(amount(percentage), separator),
+        ))),
+    )(input)?;
+    Ok((
+        input,
+        Mix {
+            with,
+            amount,
+            space: space.unwrap_or_default(),
+        },
+    ))
+}
+
+/// `<modifier>-<amount>-`
+///
+/// The separator after the amount is part of the [cut](cut),
+/// so a missing separator is reported where it is missing.
+fn modifier(input: &str) -> ParseResult<'_, Modifier> {
+    alt((
+        preceded(
+            keyword("lighten-"),
+            cut(terminated(
+                amount(|amount| Some(Modifier::Lighten(percentage(amount)?))),
+                separator,
+            )),
+        ),
+        preceded(
+            keyword("darken-"),
+            cut(terminated(
+                amount(|amount| Some(Modifier::Darken(percentage(amount)?))),
+                separator,
+            )),
+        ),
+        preceded(
+            keyword("saturate-"),
+            cut(terminated(
+                amount(|amount| Some(Modifier::Saturate(percentage(amount)?))),
+                separator,
+            )),
+        ),
+        preceded(
+            keyword("desaturate-"),
+            cut(terminated(
+                amount(|amount| Some(Modifier::Desaturate(percentage(amount)?))),
+                separator,
+            )),
+        ),
+        preceded(
+            keyword("rotate-"),
+            cut(terminated(
+                amount(|amount| (amount <= 360).then_some(Modifier::Rotate(amount))),
+                separator,
+            )),
+        ),
+    ))(input)
+}
+
+/// `fg-[<level>-]`, if no level is given [`ContrastLevel::Aa`] is used.
+fn foreground(input: &str) -> ParseResult<'_, ContrastLevel> {
+    preceded(
+        keyword("fg-"),
+        opt(terminated(
+            alt((
+                keyword("aa-large").map(|_| ContrastLevel::AaLarge),
+                keyword("aaa").map(|_| ContrastLevel::Aaa),
+                keyword("aa").map(|_| ContrastLevel::Aa),
+            )),
+            separator,
+        )),
+    )
+    .map(Option::unwrap_or_default)
+    .parse(input)
+}
+
+/// Formats and their components, followed by a [number format](number_format)
+/// if the format writes a floating point number,
+/// which is a [fraction format](fraction_format) if the component is a fraction.
+///
+/// Both are tried in order, so if a name is the prefix of another name, the longer one
+/// has to come first.
+fn format(input: &str) -> ParseResult<'_, (Format, NumberFormat)> {
+    kind(
+        ColorFieldErrorKind::Format,
         alt((
-            tag("hex")
+            keyword("hex")
+                .and(alt((
+                    component(alt((
+                        keyword("-bgr").map(|_| Hex::Bgr),
+                        keyword("-rgba").map(|_| Hex::Rgba),
+                        keyword("-argb").map(|_| Hex::Argb),
+                        keyword("-r").map(|_| Hex::R),
+                        keyword("-g").map(|_| Hex::G),
+                        keyword("-b").map(|_| Hex::B),
+                        keyword("-a").map(|_| Hex::A),
+                    )))
+                    .and(hex_style),
+                    hex_style.map(|style| (Hex::Rgb, style)),
+                )))
+                .map(|(_, (hex, style))| plain(Format::Hex(hex, style))),
+            keyword("rgb16")
+                .and(component(alt((
+                    keyword("-r").map(|_| Rgb16::R),
+                    keyword("-g").map(|_| Rgb16::G),
+                    keyword("-b").map(|_| Rgb16::B),
+                    keyword("-a").map(|_| Rgb16::A),
+                ))))
+                .map(|(_, rgb16)| plain(Format::Rgb16(rgb16))),
+            keyword("rgb")
+                .and(component(alt((
+                    keyword("-r").map(|_| Rgb::R),
+                    keyword("-g").map(|_| Rgb::G),
+                    keyword("-b").map(|_| Rgb::B),
+                    keyword("-a").map(|_| Rgb::A),
+                ))))
+                .map(|(_, rgb)| plain(Format::Rgb(rgb))),
+            keyword("dec")
+                .and(component(alt((
+                    keyword("-r").map(|_| Dec::R),
+                    keyword("-g").map(|_| Dec::G),
+                    keyword("-b").map(|_| Dec::B),
+                    keyword("-a").map(|_| Dec::A),
+                ))))
+                .and(fraction_format)
+                .map(|((_, dec), number_format)| (Format::Dec(dec), number_format)),
+            keyword("xrgb").map(|_| plain(Format::Xrgb)),
+            keyword("float")
+                .and(component(alt((
+                    keyword("-r").map(|_| Float::R),
+                    keyword("-g").map(|_| Float::G),
+                    keyword("-b").map(|_| Float::B),
+                    keyword("-a").map(|_| Float::A),
+                ))))
+                .and(fraction_format)
+                .map(|((_, float), number_format)| (Format::Float(float), number_format)),
+            keyword("hsl")
                 .and(alt((
-                    tag("-bgr").map(|_| Hex::Bgr),
-                    tag("-r").map(|_| Hex::R),
-                    tag("-g").map(|_| Hex::G),
-                    tag("-b").map(|_| Hex::B),
-                    tag("").map(|_| Hex::Rgb),
+                    component(keyword("-h").map(|_| Hsl::H)).and(number_format),
+                    component(alt((
+                        keyword("-s").map(|_| Hsl::S),
+                        keyword("-l").map(|_| Hsl::L),
+                    )))
+                    .and(fraction_format),
                 )))
-                .map(|(_, hex)| Format::Hex(hex)),
-            tag("rgb")
+                .map(|(_, (hsl, number_format))| (Format::Hsl(hsl), number_format)),
+            keyword("hsv")
                 .and(alt((
-                    tag("-r").map(|_| Rgb::R),
-                    tag("-g").map(|_| Rgb::G),
-                    tag("-b").map(|_| Rgb::B),
+                    component(keyword("-h").map(|_| Hsv::H)).and(number_format),
+                    component(alt((
+                        keyword("-s").map(|_| Hsv::S),
+                        keyword("-v").map(|_| Hsv::V),
+                    )))
+                    .and(fraction_format),
                 )))
-                .map(|(_, rgb)| Format::Rgb(rgb)),
-            tag("dec")
+                .map(|(_, (hsv, number_format))| (Format::Hsv(hsv), number_format)),
+            keyword("hwb")
                 .and(alt((
-                    tag("-r").map(|_| Dec::R),
-                    tag("-g").map(|_| Dec::G),
-                    tag("-b").map(|_| Dec::B),
+                    component(keyword("-h").map(|_| Hwb::H)).and(number_format),
+                    component(alt((
+                        keyword("-w").map(|_| Hwb::W),
+                        keyword("-b").map(|_| Hwb::B),
+                    )))
+                    .and(fraction_format),
                 )))
-                .map(|(_, dec)| Format::Dec(dec)),
-            tag("hsl")
+                .map(|(_, (hwb, number_format))| (Format::Hwb(hwb), number_format)),
+            keyword("oklab")
                 .and(alt((
-                    tag("-h").map(|_| Hsl::H),
-                    tag("-s").map(|_| Hsl::S),
-                    tag("-l").map(|_| Hsl::L),
+                    component(keyword("-l").map(|_| Oklab::L)).and(fraction_format),
+                    component(alt((
+                        keyword("-a").map(|_| Oklab::A),
+                        keyword("-b").map(|_| Oklab::B),
+                    )))
+                    .and(number_format),
                 )))
-                .map(|(_, hsl)| Format::Hsl(hsl)),
+                .map(|(_, (oklab, number_format))| (Format::Oklab(oklab), number_format)),
+            keyword("oklch")
+                .and(alt((
+                    alt((
+                        component(keyword("-l").map(|_| Oklch::L)).and(fraction_format),
+                        component(alt((
+                            keyword("-c").map(|_| Oklch::C),
+                            keyword("-h").map(|_| Oklch::H),
+                        )))
+                        .and(number_format),
+                    ))
+                    .map(|(oklch, number_format)| (Format::Oklch(oklch), number_format)),
+                    keyword("").map(|_| plain(Format::Oklch(Oklch::Function))),
+                )))
+                .map(|(_, format)| format),
+            keyword("lab")
+                .and(component(alt((
+                    keyword("-l").map(|_| Lab::L),
+                    keyword("-a").map(|_| Lab::A),
+                    keyword("-b").map(|_| Lab::B),
+                ))))
+                .and(number_format)
+                .map(|((_, lab), number_format)| (Format::Lab(lab), number_format)),
+            keyword("lch")
+                .and(component(alt((
+                    keyword("-l").map(|_| Lch::L),
+                    keyword("-c").map(|_| Lch::C),
+                    keyword("-h").map(|_| Lch::H),
+                ))))
+                .and(number_format)
+                .map(|((_, lch), number_format)| (Format::Lch(lch), number_format)),
+            keyword("xyz")
+                .and(component(alt((
+                    keyword("-x").map(|_| Xyz::X),
+                    keyword("-y").map(|_| Xyz::Y),
+                    keyword("-z").map(|_| Xyz::Z),
+                ))))
+                .and(fraction_format)
+                .map(|((_, xyz), number_format)| (Format::Xyz(xyz), number_format)),
+            keyword("ansi256").map(|_| plain(Format::Ansi256)),
+            keyword("ansi16").map(|_| plain(Format::Ansi16)),
+            keyword("css")
+                .and(component(alt((
+                    keyword("-hexa").map(|_| Css::Hexa),
+                    keyword("-hex").map(|_| Css::Hex),
+                    keyword("-rgba").map(|_| Css::Rgba),
+                    keyword("-rgb").map(|_| Css::Rgb),
+                    keyword("-hsla").map(|_| Css::Hsla),
+                    keyword("-hsl").map(|_| Css::Hsl),
+                ))))
+                .map(|(_, css)| plain(Format::Css(css))),
+            keyword("int")
+                .and(radix)
+                .map(|(_, radix)| plain(Format::Packed(Packed::Rgb, radix))),
+            keyword("argb32")
+                .and(radix)
+                .map(|(_, radix)| plain(Format::Packed(Packed::Argb32, radix))),
+            keyword("colorref")
+                .and(radix)
+                .map(|(_, radix)| plain(Format::Packed(Packed::Colorref, radix))),
         )),
-    ))(input)?;
-    Ok((input, (number, format)))
+    )(input)
+}
+
+/// a format that doesn't accept a [`NumberFormat`]
+fn plain(format: Format) -> (Format, NumberFormat) {
+    (format, NumberFormat::default())
+}
+
+// The optional suffixes below end the field. Instead of skipping a suffix with `opt`,
+// each one is an alternative to the rest of the field, so if the field continues with
+// something unknown, the error lists the skipped suffixes as expected as well.
+
+/// `[-hash|-0x][-upper]` ending a hex format
+fn hex_style(input: &str) -> ParseResult<'_, HexStyle> {
+    let uppercase = || alt((keyword("-upper").and(end).map(|_| true), end.map(|_| false)));
+
+    alt((
+        alt((
+            keyword("-hash").map(|_| HexPrefix::Hash),
+            keyword("-0x").map(|_| HexPrefix::ZeroX),
+        ))
+        .and(uppercase()),
+        uppercase().map(|uppercase| (HexPrefix::None, uppercase)),
+    ))
+    .map(|(prefix, uppercase)| HexStyle { prefix, uppercase })
+    .parse(input)
+}
+
+/// `[-hex]` ending a packed format
+fn radix(input: &str) -> ParseResult<'_, Radix> {
+    alt((
+        keyword("-hex").and(end).map(|_| Radix::Hex),
+        end.map(|_| Radix::Dec),
+    ))(input)
+}
+
+/// `[-pct][-int|-p<precision>]` ending a component that is a fraction between 0 and 1
+fn fraction_format(input: &str) -> ParseResult<'_, NumberFormat> {
+    alt((
+        keyword("-pct")
+            .and(precision)
+            .map(|(_, precision)| NumberFormat {
+                precision,
+                percent: true,
+            }),
+        number_format,
+    ))(input)
+}
+
+/// `[-int|-p<precision>]` ending a component that is a floating point number,
+/// but not a fraction, like a hue
+fn number_format(input: &str) -> ParseResult<'_, NumberFormat> {
+    precision
+        .map(|precision| NumberFormat {
+            precision,
+            percent: false,
+        })
+        .parse(input)
+}
+
+/// `[-int|-p<precision>]`
+fn precision(input: &str) -> ParseResult<'_, Option<u8>> {
+    alt((
+        alt((
+            keyword("-int").map(|_| 0),
+            preceded(
+                keyword("-p"),
+                cut(kind(
+                    ColorFieldErrorKind::Precision,
+                    map_res(digit1, str::parse),
+                )),
+            ),
+        ))
+        .and(end)
+        .map(|(precision, _)| Some(precision)),
+        end.map(|_| None),
+    ))(input)
+}
+
+/// end of the field
+fn end(input: &str) -> ParseResult<'_, ()> {
+    match input {
+        "" => Ok((input, ())),
+        _ => Err(nom::Err::Error(FieldError {
+            input,
+            kind: Some(ColorFieldErrorKind::TrailingInput),
+            expected: Vec::new(),
+        })),
+    }
+}
+
+/// Like [`tag`](nom::bytes::complete::tag), but `name` is recorded as expected alternative
+/// if it doesn't match.
+fn keyword<'a>(name: &'static str) -> impl Fn(&'a str) -> ParseResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(name) {
+        Some(rest) => Ok((rest, &input[..name.len()])),
+        None => Err(nom::Err::Error(FieldError {
+            input,
+            kind: None,
+            expected: vec![Cow::Borrowed(name)],
+        })),
+    }
+}
+
+/// Set the kind of the errors returned by `parser` which don't have one yet.
+fn kind<'a, O>(
+    kind: ColorFieldErrorKind,
+    mut parser: impl Parser<&'a str, O, FieldError<'a>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, O> {
+    move |input| {
+        parser.parse(input).map_err(|error| {
+            error.map(|mut error| {
+                error.kind.get_or_insert(kind);
+                error
+            })
+        })
+    }
+}
+
+fn component<'a, O>(
+    parser: impl Parser<&'a str, O, FieldError<'a>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, O> {
+    kind(ColorFieldErrorKind::Component, parser)
+}
+
+/// error of the parsers, turned into a [`ColorFieldError`] once parsing is finished
+#[derive(Debug)]
+struct FieldError<'a> {
+    input: &'a str,
+    kind: Option<ColorFieldErrorKind>,
+    expected: Vec<Cow<'static, str>>,
+}
+
+impl FieldError<'_> {
+    fn into_color_field_error(self, input: &str) -> ColorFieldError {
+        ColorFieldError {
+            kind: self.kind.unwrap_or_default(),
+            offset: input.len() - self.input.len(),
+            expected: self.expected,
+        }
+    }
+}
+
+impl<'a> ParseError<&'a str> for FieldError<'a> {
+    fn from_error_kind(input: &'a str, _kind: ErrorKind) -> Self {
+        Self {
+            input,
+            kind: None,
+            expected: Vec::new(),
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    /// Keep the error that got further, or merge the alternatives of both if they failed at the
+    /// same position.
+    ///
+    /// If one of them could have ended the field there, the merged error is trailing input.
+    fn or(mut self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => {
+                self.kind = match (self.kind, other.kind) {
+                    (_, Some(ColorFieldErrorKind::TrailingInput)) => other.kind,
+                    (kind, other) => kind.or(other),
+                };
+                self.expected.extend(other.expected);
+                self
+            }
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for FieldError<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _error: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
 }
 
 /// Error returned by [`ColorField::from_str`]
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ColorFieldError;
+///
+/// It contains which [part](ColorFieldErrorKind) of the field failed to parse,
+/// the byte offset in the parsed string at which it failed
+/// and the alternatives that would have been accepted at that position.
+///
+/// # Examples
+///
+/// ```rust
+/// use base16_color_scheme::template::color_field::{
+///     ColorField, ColorFieldError, ColorFieldErrorKind,
+/// };
+///
+/// let error = "base0G-hex".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.kind(), ColorFieldErrorKind::Index);
+/// assert_eq!(error.offset(), 4);
+///
+/// let error = "base08-lighten-200-hex".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.kind(), ColorFieldErrorKind::Amount);
+/// assert_eq!(error.offset(), 15);
+///
+/// // the separator after the amount is missing, not the format
+/// let error = "base08-lighten-10".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.kind(), ColorFieldErrorKind::Separator);
+/// assert_eq!(error.offset(), 17);
+///
+/// let error = "base08-cmyk-c".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.kind(), ColorFieldErrorKind::Format);
+/// assert_eq!(error.offset(), 7);
+/// assert_eq!(
+///     error.expected(),
+///     [
+///         "hex", "rgb16", "rgb", "dec", "xrgb", "float", "hsl", "hsv", "hwb", "oklab", "oklch",
+///         "lab", "lch", "xyz", "ansi256", "ansi16", "css", "int", "argb32", "colorref"
+///     ]
+/// );
+///
+/// let error = "base08-rgb-x".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.kind(), ColorFieldErrorKind::Component);
+/// assert_eq!(error.offset(), 10);
+/// assert_eq!(error.expected(), ["-r", "-g", "-b", "-a"]);
+/// assert_eq!(
+///     error.to_string(),
+///     r#"unknown component at byte 10, expected one of "-r", "-g", "-b", "-a""#
+/// );
+///
+/// // a complete field followed by more input lists the suffixes that could follow
+/// let error = "base08-hex-foo".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.kind(), ColorFieldErrorKind::TrailingInput);
+/// assert_eq!(error.offset(), 10);
+/// assert_eq!(
+///     error.expected(),
+///     ["-bgr", "-rgba", "-argb", "-r", "-g", "-b", "-a", "-hash", "-0x", "-upper"]
+/// );
+///
+/// let error = "base08-hex-hash-lower".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.offset(), 15);
+/// assert_eq!(error.expected(), ["-upper"]);
+///
+/// let error = "base08-hsl-s-x".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.offset(), 12);
+/// assert_eq!(error.expected(), ["-pct", "-int", "-p"]);
+///
+/// let error = "base08-rgb-r-p2".parse::<ColorField>().unwrap_err();
+/// assert_eq!(error.kind(), ColorFieldErrorKind::TrailingInput);
+/// assert_eq!(error.offset(), 12);
+/// assert!(error.expected().is_empty());
+///
+/// assert_eq!(
+///     ColorFieldError::default().kind(),
+///     ColorFieldErrorKind::Prefix
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColorFieldError {
+    kind: ColorFieldErrorKind,
+    offset: usize,
+    expected: Vec<Cow<'static, str>>,
+}
+
+/// part of a [`ColorField`] that failed to parse, see [`ColorFieldError`]
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum ColorFieldErrorKind {
+    /// the field doesn't start with `base`
+    #[default]
+    Prefix,
+    /// `base` isn't followed by two hex digits
+    Index,
+    /// a `-` is missing
+    Separator,
+    /// the amount of a mix or modifier isn't a number or out of range
+    Amount,
+    /// the format is unknown
+    Format,
+    /// the component of the format is unknown
+    Component,
+    /// `-p` isn't followed by a number between 0 and 255
+    Precision,
+    /// the field continues after a complete color description
+    TrailingInput,
+}
+
+impl ColorFieldError {
+    pub fn kind(&self) -> ColorFieldErrorKind {
+        self.kind
+    }
+
+    /// byte offset in the parsed string at which parsing failed
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// alternatives that would have been accepted at [`Self::offset`]
+    pub fn expected(&self) -> &[Cow<'static, str>] {
+        &self.expected
+    }
+}
+
+impl Display for ColorFieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            ColorFieldErrorKind::Prefix => "missing prefix",
+            ColorFieldErrorKind::Index => "invalid index",
+            ColorFieldErrorKind::Separator => "missing separator",
+            ColorFieldErrorKind::Amount => "invalid amount",
+            ColorFieldErrorKind::Format => "unknown format",
+            ColorFieldErrorKind::Component => "unknown component",
+            ColorFieldErrorKind::Precision => "invalid precision",
+            ColorFieldErrorKind::TrailingInput => "unexpected trailing input",
+        })?;
+        write!(f, " at byte {}", self.offset)?;
+
+        match self.kind {
+            ColorFieldErrorKind::Index => f.write_str(", expected a hex number between 00 and FF"),
+            ColorFieldErrorKind::Amount => f.write_str(
+                ", expected a percentage between 0 and 100 or degrees between 0 and 360",
+            ),
+            ColorFieldErrorKind::Precision => f.write_str(", expected a number between 0 and 255"),
+            _ if self.expected.is_empty() => Ok(()),
+            _ => {
+                f.write_str(", expected one of")?;
+                for (i, name) in self.expected.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{separator}{name:?}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for ColorFieldError {}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// minimum [contrast ratio](crate::scheme::RgbColor::contrast_ratio) of a readable foreground
+/// as defined by [WCAG 2](https://www.w3.org/TR/WCAG21/#contrast-minimum)
+///
+/// see [`Scheme::readable_foreground`](crate::Scheme::readable_foreground)
+#[derive(
+    Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum ContrastLevel {
+    /// 3:1, for large text (`fg-aa-large`)
+    AaLarge,
+    /// 4.5:1 (`fg`, `fg-aa`)
+    #[default]
+    Aa,
+    /// 7:1 (`fg-aaa`)
+    Aaa,
+}
+
+impl ContrastLevel {
+    pub fn ratio(&self) -> f64 {
+        match self {
+            Self::AaLarge => 3.0,
+            Self::Aa => 4.5,
+            Self::Aaa => 7.0,
+        }
+    }
+}
@@ -1,13 +1,38 @@
+use crate::scheme::{RgbColor, RgbaColor};
 use serde::{Deserialize, Serialize};
 
+pub mod ansi;
 pub mod hsl;
+pub mod hsv;
+pub mod hwb;
+pub mod lab;
+pub mod oklab;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Format {
-    Hex(Hex),
+    Hex(Hex, HexStyle),
     Rgb(Rgb),
+    Rgb16(Rgb16),
+    /// X11 color specification with 16-bit channels (`rgb:7c7c/afaf/c2c2`)
+    Xrgb,
     Dec(Dec),
+    Float(Float),
     Hsl(Hsl),
+    Hsv(Hsv),
+    Hwb(Hwb),
+    Oklab(Oklab),
+    Oklch(Oklch),
+    Lab(Lab),
+    Lch(Lch),
+    Xyz(Xyz),
+    /// index of the nearest color in the xterm 256 color palette
+    Ansi256,
+    /// index of the nearest of the 16 ANSI colors
+    Ansi16,
+    /// the color packed into a single integer
+    Packed(Packed, Radix),
+    /// the color as a [CSS Color 4](https://www.w3.org/TR/css-color-4/) string
+    Css(Css),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -17,6 +42,63 @@ pub enum Hex {
     G,
     B,
     Bgr,
+    A,
+    Rgba,
+    Argb,
+}
+
+/// precision and unit of the number written by a [`Format`]
+///
+/// The default uses the precision of the format and no unit.
+#[derive(
+    Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct NumberFormat {
+    /// number of decimals (`-p6`, `-int` for `0`)
+    pub precision: Option<u8>,
+    /// write values that lie between 0 and 1 as percentage (`-pct`)
+    pub percent: bool,
+}
+
+/// case and prefix of a [`Format::Hex`]
+///
+/// The default is lowercase without prefix.
+#[derive(
+    Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct HexStyle {
+    pub prefix: HexPrefix,
+    pub uppercase: bool,
+}
+
+impl HexStyle {
+    /// lowercase without prefix, usable in const contexts
+    pub const DEFAULT: Self = Self {
+        prefix: HexPrefix::None,
+        uppercase: false,
+    };
+}
+
+#[derive(
+    Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum HexPrefix {
+    #[default]
+    None,
+    /// `#`
+    Hash,
+    /// `0x`
+    ZeroX,
+}
+
+impl HexPrefix {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Hash => "#",
+            Self::ZeroX => "0x",
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -24,6 +106,16 @@ pub enum Rgb {
     R,
     G,
     B,
+    A,
+}
+
+/// decimal 16-bit channels (`0..=65535`)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rgb16 {
+    R,
+    G,
+    B,
+    A,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -31,6 +123,16 @@ pub enum Dec {
     R,
     G,
     B,
+    A,
+}
+
+/// channels as floats between `0.0` and `1.0` with full precision
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Float {
+    R,
+    G,
+    B,
+    A,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -39,3 +141,107 @@ pub enum Hsl {
     S,
     L,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Hsv {
+    H,
+    S,
+    V,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Hwb {
+    H,
+    W,
+    B,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Oklab {
+    L,
+    A,
+    B,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Oklch {
+    L,
+    C,
+    H,
+    /// all components in CSS functional notation (`oklch(0.72 0.06 220.1)`)
+    Function,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lab {
+    L,
+    A,
+    B,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lch {
+    L,
+    C,
+    H,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Xyz {
+    X,
+    Y,
+    Z,
+}
+
+/// layout of a [`Format::Packed`] integer
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Packed {
+    /// `0xRRGGBB`
+    Rgb,
+    /// `0xAARRGGBB`
+    Argb32,
+    /// `0x00BBGGRR`, like the Windows `COLORREF`
+    Colorref,
+}
+
+impl Packed {
+    pub fn pack(&self, color: RgbaColor) -> u32 {
+        let RgbaColor {
+            color: RgbColor([r, g, b]),
+            alpha: a,
+        } = color;
+        match self {
+            Self::Rgb => u32::from_be_bytes([0, r, g, b]),
+            Self::Argb32 => u32::from_be_bytes([a, r, g, b]),
+            Self::Colorref => u32::from_be_bytes([0, b, g, r]),
+        }
+    }
+}
+
+/// how a [`Format::Packed`] integer is written
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Radix {
+    /// decimal (`8171458`)
+    Dec,
+    /// uppercase hex with `0x` prefix (`0x7CAFC2`)
+    Hex,
+}
+
+/// CSS notation of a [`Format::Css`]
+///
+/// The variants ending with `a` always include the alpha channel, the others ignore it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Css {
+    /// `#7cafc2`
+    Hex,
+    /// `#7cafc280`
+    Hexa,
+    /// `rgb(124 175 194)`
+    Rgb,
+    /// `rgb(124 175 194 / 0.5)`
+    Rgba,
+    /// `hsl(196.29 36.46% 62.35%)`
+    Hsl,
+    /// `hsl(196.29 36.46% 62.35% / 0.5)`
+    Hsla,
+}
@@ -0,0 +1,81 @@
+use crate::{convert, scheme::RgbColor};
+use serde::{Deserialize, Serialize};
+use std::{ops::RangeInclusive, sync::OnceLock};
+
+/// this implements the lookup of the nearest color in the xterm palettes
+///
+/// Colors are compared by their euclidean distance in [OKLab](super::oklab::OklabFormatter).
+/// The OKLab values of the palette are computed once and shared by all lookups.
+///
+/// - `ansi256` only considers the 6×6×6 color cube and the grayscale ramp (indices 16 to 255),
+///   since the first 16 colors are usually themed by the terminal itself.
+/// - `ansi16` considers the default xterm colors of the indices 0 to 15.
+///
+/// see [`RgbFormatter`](crate::scheme::RgbColorFormatter) for details
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AnsiFormatter {
+    pub ansi256: u8,
+    pub ansi16: u8,
+}
+
+impl AnsiFormatter {
+    pub fn from_color(color: RgbColor) -> Self {
+        Self {
+            ansi256: Self::nearest_ansi256(color),
+            ansi16: Self::nearest_ansi16(color),
+        }
+    }
+
+    /// only the [`Self::ansi256`] index of `color`
+    pub fn nearest_ansi256(color: RgbColor) -> u8 {
+        nearest(color, 16..=255)
+    }
+
+    /// only the [`Self::ansi16`] index of `color`
+    pub fn nearest_ansi16(color: RgbColor) -> u8 {
+        nearest(color, 0..=15)
+    }
+}
+
+/// index in `indices` of the palette color nearest to `color`
+fn nearest(color: RgbColor, indices: RangeInclusive<u8>) -> u8 {
+    static PALETTE: OnceLock<[[f64; 3]; 256]> = OnceLock::new();
+    let palette = PALETTE.get_or_init(|| std::array::from_fn(|index| oklab(palette(index as u8))));
+
+    let target = oklab(color);
+    indices
+        .map(|index| (index, distance(target, palette[index as usize])))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+        .unwrap()
+}
+
+/// default xterm color of `index`
+fn palette(index: u8) -> RgbColor {
+    #[rustfmt::skip]
+    const ANSI16: [[u8; 3]; 16] = [
+        [0x00, 0x00, 0x00], [0xcd, 0x00, 0x00], [0x00, 0xcd, 0x00], [0xcd, 0xcd, 0x00],
+        [0x00, 0x00, 0xee], [0xcd, 0x00, 0xcd], [0x00, 0xcd, 0xcd], [0xe5, 0xe5, 0xe5],
+        [0x7f, 0x7f, 0x7f], [0xff, 0x00, 0x00], [0x00, 0xff, 0x00], [0xff, 0xff, 0x00],
+        [0x5c, 0x5c, 0xff], [0xff, 0x00, 0xff], [0x00, 0xff, 0xff], [0xff, 0xff, 0xff],
+    ];
+    const CUBE_LEVELS: [u8; 6] = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
+
+    let rgb = match index {
+        0..=15 => ANSI16[index as usize],
+        16..=231 => {
+            let index = index - 16;
+            [index / 36, index / 6 % 6, index % 6].map(|level| CUBE_LEVELS[level as usize])
+        }
+        232..=255 => [8 + (index - 232) * 10; 3],
+    };
+    RgbColor(rgb)
+}
+
+fn oklab(color: RgbColor) -> [f64; 3] {
+    convert::linear_srgb_to_oklab(convert::linear_srgb(color))
+}
+
+fn distance([l1, a1, b1]: [f64; 3], [l2, a2, b2]: [f64; 3]) -> f64 {
+    (l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)
+}
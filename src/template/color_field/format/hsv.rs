@@ -0,0 +1,31 @@
+use crate::{scheme::RgbColor, template::color_field::HslFormatter};
+use serde::{Deserialize, Serialize};
+
+/// this implements rgb to hsv (also known as hsb) conversion
+///
+/// see [`RgbFormatter`](crate::scheme::RgbColorFormatter) for details
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+pub struct HsvFormatter {
+    pub hue: f64,
+    pub saturation: f64,
+    pub value: f64,
+}
+
+impl HsvFormatter {
+    pub fn from_color(color: RgbColor) -> Self {
+        let RgbColor([r, g, b]) = color;
+        let max = r.max(g).max(b) as f64 / 255.0;
+        let min = r.min(g).min(b) as f64 / 255.0;
+
+        let saturation = match max == 0.0 {
+            true => 0.0,
+            false => (max - min) / max,
+        };
+
+        Self {
+            hue: HslFormatter::from_color(color).hue,
+            saturation,
+            value: max,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{scheme::RgbColor, template::color_field::HslFormatter};
+use serde::{Deserialize, Serialize};
+
+/// this implements rgb to hwb conversion
+///
+/// see [`RgbFormatter`](crate::scheme::RgbColorFormatter) for details
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+pub struct HwbFormatter {
+    pub hue: f64,
+    pub whiteness: f64,
+    pub blackness: f64,
+}
+
+impl HwbFormatter {
+    pub fn from_color(color: RgbColor) -> Self {
+        let RgbColor([r, g, b]) = color;
+        let max = r.max(g).max(b) as f64 / 255.0;
+        let min = r.min(g).min(b) as f64 / 255.0;
+
+        Self {
+            hue: HslFormatter::from_color(color).hue,
+            whiteness: min,
+            blackness: 1.0 - max,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{convert, scheme::RgbColor};
+use serde::{Deserialize, Serialize};
+
+/// this implements rgb to CIE Lab conversion relative to the D65 white point
+///
+/// see [`RgbFormatter`](crate::scheme::RgbColorFormatter) for details
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+pub struct LabFormatter {
+    pub lightness: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl LabFormatter {
+    pub fn from_color(color: RgbColor) -> Self {
+        let xyz = XyzFormatter::from_color(color);
+        let [lightness, a, b] = convert::xyz_to_lab([xyz.x, xyz.y, xyz.z], convert::D65);
+        Self { lightness, a, b }
+    }
+}
+
+/// this implements rgb to CIE LCh conversion, the polar form of [CIE Lab](LabFormatter)
+///
+/// see [`RgbFormatter`](crate::scheme::RgbColorFormatter) for details
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+pub struct LchFormatter {
+    pub lightness: f64,
+    pub chroma: f64,
+    pub hue: f64,
+}
+
+impl LchFormatter {
+    pub fn from_color(color: RgbColor) -> Self {
+        let LabFormatter { lightness, a, b } = LabFormatter::from_color(color);
+        let [lightness, chroma, hue] = convert::rectangular_to_polar([lightness, a, b]);
+        Self {
+            lightness,
+            chroma,
+            hue,
+        }
+    }
+}
+
+/// this implements rgb to CIE XYZ conversion relative to the D65 white point
+///
+/// The components are scaled so that the white point has a `y` of `1.0`.
+///
+/// see [`RgbFormatter`](crate::scheme::RgbColorFormatter) for details
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+pub struct XyzFormatter {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl XyzFormatter {
+    pub fn from_color(color: RgbColor) -> Self {
+        let [x, y, z] = convert::linear_srgb_to_xyz(convert::linear_srgb(color));
+        Self { x, y, z }
+    }
+}
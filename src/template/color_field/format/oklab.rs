@@ -0,0 +1,41 @@
+use crate::{convert, scheme::RgbColor};
+use serde::{Deserialize, Serialize};
+
+/// this implements rgb to [OKLab](https://bottosson.github.io/posts/oklab/) conversion
+///
+/// see [`RgbFormatter`](crate::scheme::RgbColorFormatter) for details
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+pub struct OklabFormatter {
+    pub lightness: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl OklabFormatter {
+    pub fn from_color(color: RgbColor) -> Self {
+        let [lightness, a, b] = convert::linear_srgb_to_oklab(convert::linear_srgb(color));
+        Self { lightness, a, b }
+    }
+}
+
+/// this implements rgb to OKLCH conversion, the polar form of [OKLab](OklabFormatter)
+///
+/// see [`RgbFormatter`](crate::scheme::RgbColorFormatter) for details
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+pub struct OklchFormatter {
+    pub lightness: f64,
+    pub chroma: f64,
+    pub hue: f64,
+}
+
+impl OklchFormatter {
+    pub fn from_color(color: RgbColor) -> Self {
+        let OklabFormatter { lightness, a, b } = OklabFormatter::from_color(color);
+        let [lightness, chroma, hue] = convert::rectangular_to_polar([lightness, a, b]);
+        Self {
+            lightness,
+            chroma,
+            hue,
+        }
+    }
+}
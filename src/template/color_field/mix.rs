@@ -0,0 +1,75 @@
+use crate::{
+    convert,
+    scheme::{RgbColor, RgbaColor},
+};
+use serde::{Deserialize, Serialize};
+
+/// mix with another color of the same scheme, before any [modifier](super::Modifier)
+/// is applied
+///
+/// `amount` is the percentage of the color at index `with`,
+/// so `base00-mix-base0B-15-hex` is 85% `base00` and 15% `base0B`.
+///
+/// # Example
+///
+/// ```rust
+/// use base16_color_scheme::{
+///     scheme::{RgbColor, RgbaColor},
+///     template::color_field::{Mix, MixSpace},
+/// };
+///
+/// let black = RgbColor([0x00, 0x00, 0x00]).into();
+/// let white = RgbColor([0xff, 0xff, 0xff]).into();
+///
+/// let mix = Mix { with: 0x07, amount: 50, space: MixSpace::Srgb };
+/// assert_eq!(mix.apply(black, white), RgbColor([0x80, 0x80, 0x80]).into());
+///
+/// let mix = Mix { with: 0x07, amount: 50, space: MixSpace::Oklab };
+/// assert_eq!(mix.apply(black, white), RgbColor([0x63, 0x63, 0x63]).into());
+///
+/// // the alpha channel is mixed linearly
+/// let transparent = RgbaColor::new([0x00, 0x00, 0x00], 0x00);
+/// let mix = Mix { with: 0x07, amount: 50, space: MixSpace::Srgb };
+/// assert_eq!(mix.apply(transparent, white).alpha, 0x80);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mix {
+    pub with: u8,
+    pub amount: u8,
+    pub space: MixSpace,
+}
+
+/// color space in which a [`Mix`] interpolates
+#[derive(
+    Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum MixSpace {
+    /// linear interpolation of the gamma encoded sRGB channels (`mix`, `mix-srgb`)
+    #[default]
+    Srgb,
+    /// perceptual interpolation in [OKLab](super::OklabFormatter) (`mix-oklab`)
+    Oklab,
+}
+
+impl Mix {
+    /// Mix `color` with `other`, which is the color at index [`Self::with`].
+    pub fn apply(&self, color: RgbaColor, other: RgbaColor) -> RgbaColor {
+        let amount = self.amount as f64 / 100.0;
+        let lerp = |from: f64, to: f64| from + (to - from) * amount;
+        let lerp3 = |from: [f64; 3], to: [f64; 3]| [0, 1, 2].map(|i| lerp(from[i], to[i]));
+
+        let alpha = lerp(color.alpha as f64, other.alpha as f64) / 255.0;
+        let srgb = match self.space {
+            MixSpace::Srgb => {
+                let channels = |RgbColor(rgb): RgbColor| rgb.map(|channel| channel as f64 / 255.0);
+                lerp3(channels(color.color), channels(other.color))
+            }
+            MixSpace::Oklab => {
+                let oklab = |color| convert::linear_srgb_to_oklab(convert::linear_srgb(color));
+                convert::oklab_to_linear_srgb(lerp3(oklab(color.color), oklab(other.color)))
+                    .map(convert::from_linear)
+            }
+        };
+        convert::clip(srgb).with_alpha(convert::clip_channel(alpha))
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{convert, scheme::RgbColor, template::color_field::HslFormatter};
+use serde::{Deserialize, Serialize};
+
+/// transformation applied to a color before it is formatted
+///
+/// All transformations work in HSL, percentages are absolute percentage points
+/// (so `Lighten(10)` turns a lightness of 40% into 50%) and the results are clamped
+/// to the valid range.
+///
+/// # Example
+///
+/// ```rust
+/// use base16_color_scheme::{scheme::RgbColor, template::color_field::Modifier};
+///
+/// let color = RgbColor([0x7c, 0xaf, 0xc2]);
+///
+/// assert_eq!(Modifier::Lighten(10).apply(color), RgbColor([0x9f, 0xc4, 0xd2]));
+/// assert_eq!(Modifier::Darken(100).apply(color), RgbColor([0x00, 0x00, 0x00]));
+/// assert_eq!(Modifier::Desaturate(100).apply(color), RgbColor([0x9f, 0x9f, 0x9f]));
+/// assert_eq!(Modifier::Rotate(180).apply(color), RgbColor([0xc2, 0x8f, 0x7c]));
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Modifier {
+    /// increase the lightness by the percentage (`lighten-10`)
+    Lighten(u8),
+    /// decrease the lightness by the percentage (`darken-10`)
+    Darken(u8),
+    /// increase the saturation by the percentage (`saturate-10`)
+    Saturate(u8),
+    /// decrease the saturation by the percentage (`desaturate-10`)
+    Desaturate(u8),
+    /// rotate the hue by the degrees (`rotate-30`)
+    Rotate(u16),
+}
+
+impl Modifier {
+    pub fn apply(&self, color: RgbColor) -> RgbColor {
+        let HslFormatter {
+            hue,
+            luminance,
+            saturation,
+        } = HslFormatter::from_color(color);
+        let percent = |amount: u8| amount as f64 / 100.0;
+
+        let hsl = match *self {
+            Self::Lighten(amount) => [hue, saturation, luminance + percent(amount)],
+            Self::Darken(amount) => [hue, saturation, luminance - percent(amount)],
+            Self::Saturate(amount) => [hue, saturation + percent(amount), luminance],
+            Self::Desaturate(amount) => [hue, saturation - percent(amount), luminance],
+            Self::Rotate(degrees) => [hue + degrees as f64, saturation, luminance],
+        };
+        let [hue, saturation, luminance] = hsl;
+
+        convert::clip(convert::hsl_to_srgb([
+            hue,
+            saturation.clamp(0.0, 1.0),
+            luminance.clamp(0.0, 1.0),
+        ]))
+    }
+}
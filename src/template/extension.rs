@@ -0,0 +1,239 @@
+use crate::{
+    scheme::RgbColor,
+    template::{color_field::parse_field_color, TemplateField},
+    Scheme,
+};
+use ramhorns::{encoding::Encoder, Content, Section, Template};
+use std::fmt::{self, Display, Formatter};
+
+/// custom color format that can be used in templates rendered with a [`RenderContext`]
+///
+/// Fields of a custom format look like `base<index>-<name><component>`,
+/// where `<component>` is everything after the [name](FormatExtension::name).
+/// Like the built-in formats, the name can be preceded by a mix, a modifier and `fg`,
+/// e.g. `base08-darken-20-<name>`. (see [`ColorField`](crate::template::color_field::ColorField))
+/// They are only looked up if the field isn't a valid built-in field.
+///
+/// # Example
+///
+/// ```rust
+/// use base16_color_scheme::{
+///     scheme::RgbColor,
+///     template::extension::{FormatExtension, RenderContext},
+///     Scheme, Template,
+/// };
+/// use std::fmt::{self, Formatter};
+///
+/// /// color code of an LED controller, `led` for the color and `led-dim` for half brightness
+/// struct Led;
+///
+/// impl FormatExtension for Led {
+///     fn name(&self) -> &str {
+///         "led"
+///     }
+///
+///     fn parse(&self, component: &str) -> bool {
+///         matches!(component, "" | "-dim")
+///     }
+///
+///     fn write(&self, component: &str, color: RgbColor, f: &mut Formatter<'_>) -> fmt::Result {
+///         let RgbColor([r, g, b]) = color;
+///         let [r, g, b] = match component {
+///             "-dim" => [r / 2, g / 2, b / 2],
+///             _ => [r, g, b],
+///         };
+///         write!(f, "L{r:03}{g:03}{b:03}")
+///     }
+/// }
+///
+/// let scheme: Scheme = serde_yaml::from_str("
+/// scheme: Scheme Name
+/// author: Scheme Author
+/// base08: 7cafc2
+/// ").unwrap();
+/// let template = Template::new("\
+/// {{base08-led}} {{base08-led-dim}} {{base08-darken-100-led}} {{base08-hex}} \
+/// {{#base08-led}}has base08{{/base08-led}} {{^base10-led}}no base10{{/base10-led}}").unwrap();
+///
+/// let context = RenderContext::new(&scheme).with_extension(Led);
+/// assert_eq!(
+///     template.render(&context),
+///     "L124175194 L062087097 L000000000 7cafc2 has base08 no base10"
+/// );
+///
+/// // without the context the fields are unknown
+/// assert_eq!(template.render(&scheme), "   7cafc2  no base10");
+/// ```
+pub trait FormatExtension {
+    /// name of the format, used in fields after the index (`base08-<name>`)
+    fn name(&self) -> &str;
+
+    /// Check whether `component`, the rest of the field after the name, is valid.
+    fn parse(&self, component: &str) -> bool;
+
+    /// Write `color` in the format, `component` has already been accepted by
+    /// [`FormatExtension::parse`].
+    fn write(&self, component: &str, color: RgbColor, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// wrapper around [`Scheme`] that renders templates with additional [formats](FormatExtension)
+///
+/// All other fields are rendered by the [`Content`] implementation of [`Scheme`].
+pub struct RenderContext<'a> {
+    pub scheme: &'a Scheme,
+    extensions: Vec<Box<dyn FormatExtension + 'a>>,
+}
+
+impl<'a> RenderContext<'a> {
+    pub fn new(scheme: &'a Scheme) -> Self {
+        Self {
+            scheme,
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Register `extension`, extensions registered earlier take precedence.
+    pub fn with_extension(mut self, extension: impl FormatExtension + 'a) -> Self {
+        self.register(extension);
+        self
+    }
+
+    /// Register `extension`, extensions registered earlier take precedence.
+    pub fn register(&mut self, extension: impl FormatExtension + 'a) {
+        self.extensions.push(Box::new(extension));
+    }
+
+    /// Look up a field of a registered extension.
+    ///
+    /// Returns `None` if the field is a built-in field or no extension accepts it,
+    /// and `Some(None)` if the extension accepts it, but the color doesn't exist.
+    fn extension_field<'n>(&self, name: &'n str) -> Option<Option<ExtensionFormatter<'_, 'n>>> {
+        if TemplateField::parse_field(name) != TemplateField::UnparsableField {
+            return None;
+        }
+
+        let (field_color, format) = parse_field_color(name)?;
+        let (extension, component) = self.extensions.iter().find_map(|extension| {
+            let component = format.strip_prefix(extension.name())?;
+            extension.parse(component).then_some((extension, component))
+        })?;
+        Some(
+            self.scheme
+                .field_color(field_color)
+                .map(|color| ExtensionFormatter {
+                    extension: extension.as_ref(),
+                    component,
+                    color: color.color,
+                }),
+        )
+    }
+}
+
+/// [`Display`] adapter for [`FormatExtension::write`]
+struct ExtensionFormatter<'e, 'n> {
+    extension: &'e dyn FormatExtension,
+    component: &'n str,
+    color: RgbColor,
+}
+
+impl Display for ExtensionFormatter<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.extension.write(self.component, self.color, f)
+    }
+}
+
+impl Content for ExtensionFormatter<'_, '_> {
+    fn is_truthy(&self) -> bool {
+        true
+    }
+
+    fn render_escaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        encoder.format_escaped(self)
+    }
+
+    fn render_unescaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        encoder.format_unescaped(self)
+    }
+}
+
+impl Content for RenderContext<'_> {
+    fn is_truthy(&self) -> bool {
+        self.scheme.is_truthy()
+    }
+
+    fn capacity_hint(&self, tpl: &Template) -> usize {
+        self.scheme.capacity_hint(tpl)
+    }
+
+    fn render_field_escaped<E: Encoder>(
+        &self,
+        hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        match self.extension_field(name) {
+            Some(Some(formatter)) => encoder.format_escaped(formatter).map(|_| true),
+            Some(None) => Ok(false),
+            None => self.scheme.render_field_escaped(hash, name, encoder),
+        }
+    }
+
+    fn render_field_unescaped<E: Encoder>(
+        &self,
+        hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        match self.extension_field(name) {
+            Some(Some(formatter)) => encoder.format_unescaped(formatter).map(|_| true),
+            Some(None) => Ok(false),
+            None => self.scheme.render_field_unescaped(hash, name, encoder),
+        }
+    }
+
+    fn render_field_section<C, E>(
+        &self,
+        hash: u64,
+        name: &str,
+        section: Section<C>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        C: ramhorns::traits::ContentSequence,
+        E: Encoder,
+    {
+        match self.extension_field(name) {
+            Some(Some(formatter)) => {
+                section.with(&formatter).render(encoder)?;
+                Ok(true)
+            }
+            Some(None) => Ok(false),
+            None => self
+                .scheme
+                .render_field_section(hash, name, section, encoder),
+        }
+    }
+
+    fn render_field_inverse<C, E>(
+        &self,
+        hash: u64,
+        name: &str,
+        section: Section<C>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        C: ramhorns::traits::ContentSequence,
+        E: Encoder,
+    {
+        match self.extension_field(name) {
+            Some(Some(_)) => Ok(true),
+            Some(None) => {
+                section.render(encoder)?;
+                Ok(true)
+            }
+            None => self
+                .scheme
+                .render_field_inverse(hash, name, section, encoder),
+        }
+    }
+}